@@ -2,20 +2,161 @@
 
 #[ink::contract]
 mod quiz {
-    use ink::env::hash::{Blake2x256, CryptoHash, HashOutput};
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::{Blake2x256, CryptoHash, HashOutput, Keccak256, Sha2x256};
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
     use scale::Encode;
 
+    /// Version of the contract, bumped whenever its storage or messages change
+    pub const VERSION: u16 = 1;
+    /// Difficulty of the easiest questions, also used when none is given
+    pub const MIN_DIFFICULTY: u8 = 1;
+    /// Difficulty of the hardest questions
+    pub const MAX_DIFFICULTY: u8 = 5;
+    /// Fewest points a correct answer to the current question earns, however slow
+    pub const MIN_TIMED_POINTS: u32 = 1;
+    /// Maximum length of a question's text, in bytes
+    pub const MAX_QUESTION_LEN: usize = 512;
+    /// Maximum length of an answer, in bytes
+    pub const MAX_ANSWER_LEN: usize = 256;
+    /// Maximum length of a question's image URI, in bytes
+    pub const MAX_IMAGE_URI_LEN: usize = 256;
+    /// Most educators a quiz may have at once, counting the owner
+    pub const MAX_EDUCATORS: u32 = 16;
+
     #[derive(scale::Decode, scale::Encode, Debug, Clone, Default)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
-    pub struct Question {
+    struct Question {
+        /// Number fixed when the question is added and mixed into its answer
+        /// hashes, so equal answers to different questions hash differently.
+        /// Keys every per-question table, so those records follow the question
+        /// when `remove_question` moves it to another index.
+        id: u32,
         question: String,
-        answer: [u8; 32],
+        /// Salted hashes of every accepted answer
+        answers: Vec<[u8; 32]>,
+        points: u32,
+        /// Options to pick from; empty for open-text questions
+        options: Vec<String>,
+        /// How answers are normalized before hashing
+        normalize: NormalizeOpts,
+        /// Last block at which scored answers are accepted
+        deadline: Option<BlockNumber>,
+        /// Plaintext `(answer, tolerance)` for numeric questions
+        numeric: Option<(i128, u128)>,
+        /// Topic the question belongs to
+        category: String,
+        /// How hard the question is, from `MIN_DIFFICULTY` to `MAX_DIFFICULTY`
+        difficulty: u8,
+        /// Scored attempts each player may make at the question
+        max_attempts: u8,
+        /// Whether scored answers are currently accepted
+        enabled: bool,
+        /// Nudge towards the answer, only shown when asked for
+        hint: Option<String>,
+        /// Where to find an image shown with the question, e.g. an IPFS CID
+        image_uri: Option<String>,
+    }
+
+    /// How an answer is normalized before being hashed, kept with each question so
+    /// attempts are normalized exactly like the stored answer
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct NormalizeOpts {
+        /// Whether letter case must match; otherwise answers are lowercased
+        pub case_sensitive: bool,
+        /// Whether surrounding whitespace is ignored
+        pub trim: bool,
+    }
+
+    impl Default for NormalizeOpts {
+        fn default() -> Self {
+            Self {
+                case_sensitive: true,
+                trim: true,
+            }
+        }
+    }
+
+    /// Puts an answer in the form it is hashed in
+    fn normalize_answer(raw: &str, opts: NormalizeOpts) -> String {
+        let answer = if opts.trim { raw.trim() } else { raw };
+        if opts.case_sensitive {
+            String::from(answer)
+        } else {
+            answer.to_lowercase()
+        }
+    }
+
+    /// What players get to see of a question, leaving out its answer
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct QuestionView {
+        pub question: String,
+        pub points: u32,
+        /// Options to pick from; empty for open-text questions
+        pub options: Vec<String>,
+        /// Topic the question belongs to
+        pub category: String,
+    }
+
+    impl From<Question> for QuestionView {
+        fn from(question: Question) -> Self {
+            Self {
+                question: question.question,
+                points: question.points,
+                options: question.options,
+                category: question.category,
+            }
+        }
+    }
+
+    /// Everything needed to add a question in one call
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct QuestionSpec {
+        pub question: String,
+        /// Plaintext answer, hashed before it is stored
+        pub answer: String,
+        /// Points the question is worth; `default_points` if `None`
+        pub points: Option<u32>,
+        pub category: String,
+        /// From `MIN_DIFFICULTY` to `MAX_DIFFICULTY`
+        pub difficulty: u8,
+        pub hint: Option<String>,
+        pub image_uri: Option<String>,
+        /// Last block at which scored answers are accepted
+        pub deadline: Option<BlockNumber>,
+    }
+
+    /// A player's stats, gathered in one call for dashboards
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PlayerProfile {
+        pub score: u32,
+        pub streak: u32,
+        /// Number of questions the player has made a scored attempt at
+        pub answered_count: u32,
+    }
+
+    /// Headline numbers about the whole quiz
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct QuizStats {
+        pub question_count: u32,
+        /// Same as `player_count`
+        pub player_count: u32,
+        /// Scored attempts made, right or wrong
+        pub total_answers: u32,
+        pub total_points_awarded: u32,
     }
 
     #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
@@ -28,7 +169,20 @@ mod quiz {
         User,
     }
 
-    #[derive(scale::Decode, scale::Encode, Debug, Clone)]
+    /// Algorithm answers are hashed with, fixed when the quiz is created
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Default)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum HashAlgo {
+        #[default]
+        Blake2x256,
+        Keccak256,
+        Sha2x256,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
@@ -38,8 +192,131 @@ mod quiz {
         QuestionDoesntExist,
         InvalidPowerLevel,
         InvalidCaller,
+        NoCommitment,
+        CommitmentMismatch,
+        AlreadyAnswered,
+        Paused,
+        InvalidOption,
+        Expired,
+        AlreadyClaimed,
+        TransferFailed,
+        NotNumeric,
+        InvalidDifficulty,
+        TooManyAttempts,
+        DuplicateQuestion,
+        EmptyField,
+        TooLong,
+        NoActiveSession,
+        RewardTransferFailed,
+        InsufficientBalance,
+        SessionActive,
+        QuestionDisabled,
+        EducatorLimitReached,
+        CooldownActive,
+        NotRegistered,
+        FeeTooLow,
+        WindowClosed,
+        NoAnswerHash,
+        InvalidWindow,
+    }
+
+    /// Errors a PSP22 token contract may return, decoded from its replies
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+        ZeroRecipientAddress,
+        ZeroSenderAddress,
+        SafeTransferCheckFailed(String),
+    }
+
+    /// Emitted when an educator adds a question
+    #[ink(event)]
+    pub struct QuestionAdded {
+        #[ink(topic)]
+        index: u32,
+        #[ink(topic)]
+        educator: AccountId,
+    }
+
+    /// Emitted whenever a player makes a scored attempt at a question
+    #[ink(event)]
+    pub struct AnswerChecked {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        index: u32,
+        correct: bool,
+    }
+
+    /// Emitted when ownership of the quiz changes hands
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous: AccountId,
+        #[ink(topic)]
+        new: AccountId,
     }
 
+    /// Emitted whenever a player's score changes
+    #[ink(event)]
+    pub struct ScoreUpdated {
+        #[ink(topic)]
+        player: AccountId,
+        new_score: u32,
+    }
+
+    /// Emitted when an account is granted educator rights
+    #[ink(event)]
+    pub struct EducatorAdded {
+        #[ink(topic)]
+        educator: AccountId,
+    }
+
+    /// Emitted when an account loses educator rights
+    #[ink(event)]
+    pub struct EducatorRemoved {
+        #[ink(topic)]
+        educator: AccountId,
+    }
+
+    /// Emitted when an account registers as a player
+    #[ink(event)]
+    pub struct PlayerRegistered {
+        #[ink(topic)]
+        player: AccountId,
+    }
+
+    /// Emitted when a question is removed; after `remove_question` the last
+    /// question takes its place
+    #[ink(event)]
+    pub struct QuestionRemoved {
+        #[ink(topic)]
+        index: u32,
+    }
+
+    /// Emitted when a session opens
+    #[ink(event)]
+    pub struct SessionStarted {
+        start_block: BlockNumber,
+    }
+
+    /// Emitted when a session closes
+    #[ink(event)]
+    pub struct SessionEnded {
+        end_block: BlockNumber,
+    }
+
+    /// Identifies a single player's interaction with a single question, by the
+    /// question's id
+    pub type PlayerQuestion = (AccountId, u32);
+
+    /// Identifies a player's scored attempts at a question within one session,
+    /// as `(session id, player, question id)`
+    pub type SessionAttempt = (u32, AccountId, u32);
+
     /// Defines the storage of your contract.
     /// Add new fields to the below struct in order
     /// to add new static storage fields to your contract.
@@ -47,150 +324,4466 @@ mod quiz {
     pub struct Quiz {
         /// Owner of quiz
         owner: AccountId,
-        /// Stores a single `bool` value on the storage.
-        questions: Vec<Question>,
+        /// Questions by index, from `0` up to (excluding) `next_index`
+        questions: Mapping<u32, Question>,
+        /// Index the next question will be stored at
+        next_index: u32,
         /// Mapping of users that register to use this contract
         actors: Mapping<AccountId, PowerLevel>,
+        /// Points earned by each player in each session
+        scores: Mapping<(u32, AccountId), u32>,
+        /// Every account that has registered or scored in any session
+        players: Vec<AccountId>,
+        /// Accounts with an entry in `scores` for each session, since `scores`
+        /// can't be iterated
+        session_players: Mapping<u32, Vec<AccountId>>,
+        /// Session currently or most recently running; `0` before the first one
+        session_id: u32,
+        /// Least value players must transfer with each answer, added to the reward pool
+        answer_fee: Balance,
+        /// First and last block, inclusive, at which scored answers are accepted
+        window: Option<(BlockNumber, BlockNumber)>,
+        /// Per-contract salt mixed into every answer hash
+        salt: [u8; 32],
+        /// Pending answer commitments per player and question
+        commitments: Mapping<PlayerQuestion, [u8; 32]>,
+        /// Whether each player's latest scored attempt at a question in each
+        /// session was correct
+        answered: Mapping<SessionAttempt, bool>,
+        /// Account that has been offered ownership but not yet accepted it
+        pending_owner: Option<AccountId>,
+        /// Whether answering and adding questions is currently frozen
+        paused: bool,
+        /// Number of players who answered each question correctly
+        correct_count: Mapping<u32, u32>,
+        /// Number of scored attempts made at each question, right or wrong
+        attempt_count: Mapping<u32, u32>,
+        /// Amount paid to the first correct answerer of each question
+        reward_per_question: Balance,
+        /// Account that claimed each question's reward in each session
+        winners: Mapping<(u32, u32), AccountId>,
+        /// Scored attempts each player has made at each question in each session
+        attempts: Mapping<SessionAttempt, u8>,
+        /// Whether questions with the same text (ignoring case) may be added
+        allow_duplicates: bool,
+        /// Whether a session is running and accepting scored answers
+        session_active: bool,
+        /// Block at which the current or most recent session started
+        session_start: BlockNumber,
+        /// Question everyone is answering in synchronized play
+        current_question: Option<u32>,
+        /// Block at which each question last became the current question
+        activated_at: Mapping<u32, BlockNumber>,
+        /// Consecutive correct scored answers of each player in each session
+        streak: Mapping<(u32, AccountId), u32>,
+        /// PSP22 contract rewards are paid in; native tokens when unset
+        reward_token: Option<AccountId>,
+        /// Name shown to players and explorers
+        title: String,
+        /// Points a question is worth when added without explicit points
+        default_points: u32,
+        /// First player to answer each question correctly
+        first_solver: Mapping<u32, AccountId>,
+        /// Algorithm answers are hashed with, both when stored and when checked
+        hash_algo: HashAlgo,
+        /// Number of accounts with educator rights, including the owner
+        educator_count: u32,
+        /// Block of each player's latest scored attempt at a question in each session
+        last_attempt: Mapping<SessionAttempt, BlockNumber>,
+        /// Blocks a player must wait between scored attempts at the same question
+        cooldown_blocks: BlockNumber,
+        /// Plaintext answers published once a session is over
+        revealed: Mapping<u32, String>,
+        /// Id the next question will be given; unlike `next_index`, never reused
+        next_id: u32,
+        /// Scored attempts made by all players, right or wrong
+        total_answers: u32,
+        /// Points awarded to all players, including since-reset scores
+        total_points_awarded: u32,
+        /// Whether each player has answered a question correctly in any session
+        solved: Mapping<PlayerQuestion, bool>,
     }
 
     impl Quiz {
         /// Creates a new quiz contract.
         #[ink(constructor)]
         pub fn new() -> Self {
-            let owner: AccountId = Self::env().caller();
+            Self::new_with_owner(Self::env().caller())
+        }
+
+        /// Creates a new quiz contract owned by `owner` rather than the deployer,
+        /// e.g. when a factory contract deploys on someone's behalf.
+        #[ink(constructor)]
+        pub fn new_with_owner(owner: AccountId) -> Self {
             let mut actors = Mapping::default();
-            // The creator of the contract is the "Owner"
+            // The owner starts out as the only educator
             actors.insert(owner, &PowerLevel::Educator);
-            let questions = Vec::new();
+            let questions = Mapping::default();
+            // Not true randomness, but unique per deployment, which is all a salt needs
+            let salt = Self::hash::<Blake2x256, _>((
+                Self::env().account_id(),
+                owner,
+                Self::env().block_number(),
+                Self::env().block_timestamp(),
+            ));
             Self {
                 questions,
+                next_index: 0,
                 actors,
                 owner,
+                scores: Mapping::default(),
+                players: Vec::new(),
+                session_players: Mapping::default(),
+                session_id: 0,
+                answer_fee: 0,
+                window: None,
+                salt,
+                commitments: Mapping::default(),
+                answered: Mapping::default(),
+                pending_owner: None,
+                paused: false,
+                correct_count: Mapping::default(),
+                attempt_count: Mapping::default(),
+                reward_per_question: 0,
+                winners: Mapping::default(),
+                attempts: Mapping::default(),
+                allow_duplicates: false,
+                session_active: false,
+                session_start: 0,
+                current_question: None,
+                activated_at: Mapping::default(),
+                streak: Mapping::default(),
+                reward_token: None,
+                title: String::new(),
+                default_points: 1,
+                first_solver: Mapping::default(),
+                hash_algo: HashAlgo::default(),
+                educator_count: 1,
+                last_attempt: Mapping::default(),
+                cooldown_blocks: 0,
+                revealed: Mapping::default(),
+                next_id: 0,
+                total_answers: 0,
+                total_points_awarded: 0,
+                solved: Mapping::default(),
             }
         }
 
+        /// Creates a new quiz contract seeded with `(question, answer)` pairs,
+        /// validated like `add_questions`.
+        #[ink(constructor)]
+        pub fn new_with_questions(items: Vec<(String, String)>) -> Result<Self, Error> {
+            let mut quiz = Self::new();
+            let owner = quiz.owner;
+            quiz.push_questions(owner, items)?;
+            Ok(quiz)
+        }
+
+        /// Creates a new quiz contract named `title`.
+        #[ink(constructor)]
+        pub fn new_with_title(title: String) -> Self {
+            let mut quiz = Self::new();
+            quiz.title = title;
+            quiz
+        }
+
+        /// Creates a new quiz contract hashing answers with `hash_algo`.
+        #[ink(constructor)]
+        pub fn new_with_hash_algo(hash_algo: HashAlgo) -> Self {
+            let mut quiz = Self::new();
+            quiz.hash_algo = hash_algo;
+            quiz
+        }
+
+        /// Adds a question worth `points` points, or `default_points` if `None`.
+        /// See `add_question_full` to set its other fields.
         #[ink(message)]
-        pub fn add_question(&mut self, question: String, answer: String) -> Result<(), Error> {
-            let caller = Self::env().caller();
-            Self::ensure_powerlevel(&self, caller, PowerLevel::Educator)?;
-            let answer_hash = Self::hash::<Blake2x256, String>(answer);
-            self.questions.push(Question {
+        pub fn add_question(
+            &mut self,
+            question: String,
+            answer: String,
+            points: Option<u32>,
+        ) -> Result<(), Error> {
+            self.add_question_full(QuestionSpec {
                 question,
-                answer: answer_hash,
-            });
-            return Ok(());
+                answer,
+                points,
+                category: String::new(),
+                difficulty: MIN_DIFFICULTY,
+                hint: None,
+                image_uri: None,
+                deadline: None,
+            })
+        }
+
+        /// Adds a question described by `spec`, hashing its plaintext answer
+        #[ink(message)]
+        pub fn add_question_full(&mut self, spec: QuestionSpec) -> Result<(), Error> {
+            Self::ensure_valid_fields(&spec.question, &[&spec.answer])?;
+            if let Some(uri) = &spec.image_uri {
+                if uri.trim().is_empty() {
+                    return Err(Error::EmptyField);
+                }
+                if uri.len() > MAX_IMAGE_URI_LEN {
+                    return Err(Error::TooLong);
+                }
+            }
+            if !(MIN_DIFFICULTY..=MAX_DIFFICULTY).contains(&spec.difficulty) {
+                return Err(Error::InvalidDifficulty);
+            }
+            let points = spec.points.unwrap_or(self.default_points);
+            let entry = Question {
+                category: spec.category,
+                difficulty: spec.difficulty,
+                hint: spec.hint,
+                image_uri: spec.image_uri,
+                deadline: spec.deadline,
+                ..self.build_question(spec.question, spec.answer, points, NormalizeOpts::default())
+            };
+            self.insert_question(entry)
+        }
+
+        /// Adds a question whose answer matches regardless of letter case
+        #[ink(message)]
+        pub fn add_case_insensitive_question(
+            &mut self,
+            question: String,
+            answer: String,
+            points: u32,
+        ) -> Result<(), Error> {
+            Self::ensure_valid_fields(&question, &[&answer])?;
+            let entry = self.build_question(
+                question,
+                answer,
+                points,
+                NormalizeOpts {
+                    case_sensitive: false,
+                    ..Default::default()
+                },
+            );
+            self.insert_question(entry)
+        }
+
+        /// Adds a question worth `default_points` points
+        #[ink(message)]
+        pub fn add_question_default(
+            &mut self,
+            question: String,
+            answer: String,
+        ) -> Result<(), Error> {
+            self.add_question(question, answer, None)
+        }
+
+        /// Adds a question accepting any of `answers`, e.g. synonyms
+        #[ink(message)]
+        pub fn add_question_multi(
+            &mut self,
+            question: String,
+            answers: Vec<String>,
+        ) -> Result<(), Error> {
+            let first = answers.first().cloned().ok_or(Error::EmptyField)?;
+            let answer_refs: Vec<&str> = answers.iter().map(String::as_str).collect();
+            Self::ensure_valid_fields(&question, &answer_refs)?;
+            let entry = Question {
+                answers: answers
+                    .into_iter()
+                    .map(|answer| self.hash_answer(self.next_id, &answer, NormalizeOpts::default()))
+                    .collect(),
+                ..self.build_question(
+                    question,
+                    first,
+                    self.default_points,
+                    NormalizeOpts::default(),
+                )
+            };
+            self.insert_question(entry)
+        }
+
+        /// Adds a multiple choice question whose answer is `options[correct_index]`
+        #[ink(message)]
+        pub fn add_multiple_choice(
+            &mut self,
+            question: String,
+            options: Vec<String>,
+            correct_index: u8,
+        ) -> Result<(), Error> {
+            let correct = options
+                .get(correct_index as usize)
+                .ok_or(Error::InvalidOption)?
+                .clone();
+            Self::ensure_valid_fields(&question, &[&correct])?;
+            let entry = Question {
+                options,
+                ..self.build_question(
+                    question,
+                    correct,
+                    self.default_points,
+                    NormalizeOpts::default(),
+                )
+            };
+            self.insert_question(entry)
+        }
+
+        /// Adds a question whose answer is a number, accepting attempts within
+        /// `tolerance` of it.
+        ///
+        /// The answer is stored in plaintext, since hashing it would rule out
+        /// range comparisons.
+        #[ink(message)]
+        pub fn add_numeric_question(
+            &mut self,
+            question: String,
+            answer: i128,
+            tolerance: u128,
+        ) -> Result<(), Error> {
+            Self::ensure_valid_fields(&question, &[])?;
+            let entry = Question {
+                id: self.next_id,
+                question,
+                points: self.default_points,
+                difficulty: MIN_DIFFICULTY,
+                max_attempts: 1,
+                enabled: true,
+                numeric: Some((answer, tolerance)),
+                ..Default::default()
+            };
+            self.insert_question(entry)
+        }
+
+        /// Adds several `(question, answer)` pairs in one call
+        #[ink(message)]
+        pub fn add_questions(&mut self, items: Vec<(String, String)>) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.ensure_not_paused()?;
+            self.push_questions(caller, items)
         }
 
         #[ink(message)]
         pub fn add_educator(&mut self, educator: AccountId) -> Result<(), Error> {
             let caller = Self::env().caller();
             self.ensure_contract_owner(caller)?;
+            if self.actors.get(educator) != Some(PowerLevel::Educator) {
+                if self.educator_count >= MAX_EDUCATORS {
+                    return Err(Error::EducatorLimitReached);
+                }
+                self.educator_count += 1;
+            }
             self.actors.insert(educator, &PowerLevel::Educator);
-            Err(Error::InvalidCaller)
+            Self::env().emit_event(EducatorAdded { educator });
+            Ok(())
         }
 
-        /// Simply returns a question (if it exists)
+        /// Grants educator rights to several accounts at once, or to none of them
+        /// if that would go past `MAX_EDUCATORS`
         #[ink(message)]
-        pub fn get(&self, index: u32) -> Result<Question, Error> {
-            let question = self.questions.get(index as usize);
-            if let Some(valid_question) = question {
-                return Ok(valid_question.clone());
+        pub fn add_educators(&mut self, educators: Vec<AccountId>) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            let mut added: Vec<AccountId> = Vec::new();
+            for educator in educators {
+                if self.actors.get(educator) != Some(PowerLevel::Educator)
+                    && !added.contains(&educator)
+                {
+                    added.push(educator);
+                }
+            }
+            if self.educator_count.saturating_add(added.len() as u32) > MAX_EDUCATORS {
+                return Err(Error::EducatorLimitReached);
             }
-            Err(Error::QuestionDoesntExist)
+            self.educator_count += added.len() as u32;
+            for educator in added {
+                self.actors.insert(educator, &PowerLevel::Educator);
+                Self::env().emit_event(EducatorAdded { educator });
+            }
+            Ok(())
         }
 
-        /// Check if an answer is correct
+        /// Registers the caller as a regular user
         #[ink(message)]
-        pub fn check_answer(&self, index: u32, attempt: String) -> Result<bool, Error> {
-            let question = Self::get(&self, index)?;
-            let answer_hash = Self::hash::<Blake2x256, String>(attempt);
-            if question.answer == answer_hash {
-                return Ok(true);
+        pub fn register(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.actors.contains(caller) {
+                return Err(Error::InvalidPowerLevel);
+            }
+            self.actors.insert(caller, &PowerLevel::User);
+            if !self.players.contains(&caller) {
+                self.players.push(caller);
             }
-            Err(Error::WrongAnswer)
+            Self::env().emit_event(PlayerRegistered { player: caller });
+            Ok(())
         }
 
-        /// Hashes a value with any supported hashing algos
-        fn hash<S: CryptoHash + HashOutput, T: Encode>(entity: T) -> <S as HashOutput>::Type {
-            let mut hash = <<S as HashOutput>::Type as Default>::default();
-            <S as CryptoHash>::hash(&entity.encode(), &mut hash);
-            return hash;
+        /// Removes the caller, who must be a regular user, along with their scores
+        /// in every session, so past leaderboards agree with `player_count`
+        #[ink(message)]
+        pub fn unregister(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.actors.get(caller) != Some(PowerLevel::User) {
+                return Err(Error::InvalidCaller);
+            }
+            self.actors.remove(caller);
+            if self.scores.contains((self.session_id, caller)) {
+                Self::env().emit_event(ScoreUpdated {
+                    player: caller,
+                    new_score: 0,
+                });
+            }
+            for session_id in 0..=self.session_id {
+                self.scores.remove((session_id, caller));
+                let mut session_players = self.players_in(session_id);
+                if session_players.contains(&caller) {
+                    session_players.retain(|player| *player != caller);
+                    self.session_players.insert(session_id, &session_players);
+                }
+            }
+            self.streak.remove((self.session_id, caller));
+            self.players.retain(|player| *player != caller);
+            Ok(())
         }
 
-        fn ensure_powerlevel(&self, id: AccountId, level: PowerLevel) -> Result<(), Error> {
-            if let Some(power_level) = self.actors.get(id) {
-                if power_level == level {
-                    return Ok(());
-                }
+        /// Revokes educator rights from an account other than the owner, who is
+        /// always an educator
+        #[ink(message)]
+        pub fn remove_educator(&mut self, educator: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if educator == self.owner || self.actors.get(educator) != Some(PowerLevel::Educator) {
                 return Err(Error::InvalidPowerLevel);
             }
-            return Err(Error::InvalidCaller);
+            self.actors.remove(educator);
+            self.educator_count = self.educator_count.saturating_sub(1);
+            Self::env().emit_event(EducatorRemoved { educator });
+            Ok(())
         }
 
-        fn ensure_contract_owner(&self, id: AccountId) -> Result<(), Error> {
-            if id != self.owner {
+        /// Moves educator rights from `from` to `to` in one call. The owner's rights
+        /// can't be moved, as the owner is always an educator.
+        #[ink(message)]
+        pub fn transfer_educator(&mut self, from: AccountId, to: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if from == self.owner {
                 return Err(Error::InvalidCaller);
             }
+            if self.actors.get(from) != Some(PowerLevel::Educator) {
+                return Err(Error::InvalidPowerLevel);
+            }
+            self.actors.remove(from);
+            Self::env().emit_event(EducatorRemoved { educator: from });
+            if self.actors.get(to) == Some(PowerLevel::Educator) {
+                self.educator_count = self.educator_count.saturating_sub(1);
+            } else {
+                self.actors.insert(to, &PowerLevel::Educator);
+                Self::env().emit_event(EducatorAdded { educator: to });
+            }
             Ok(())
         }
-    }
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
-    #[cfg(test)]
-    mod tests {
-        /// Imports all the definitions from the outer scope so we can use them here.
-        use super::*;
+        /// Gives up the caller's educator rights. The owner can't renounce, as
+        /// that would leave the quiz without an educator it controls.
+        #[ink(message)]
+        pub fn renounce_educator(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if caller == self.owner {
+                return Err(Error::InvalidCaller);
+            }
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.actors.remove(caller);
+            self.educator_count = self.educator_count.saturating_sub(1);
+            Self::env().emit_event(EducatorRemoved { educator: caller });
+            Ok(())
+        }
 
-        /// We test if the default constructor does its job.
-        #[ink::test]
-        fn default_works() {
-            let quiz = Quiz::new();
-            assert!(quiz.get(0).is_err());
+        /// Returns the owner of the quiz
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.owner
         }
 
-        /// We test if creating a new contract with questions works.
-        #[ink::test]
-        fn add_questions() {
-            let mut quiz = Quiz::new();
-            quiz.add_question(String::from("What color is the sky?"), String::from("Blue"))
-                .unwrap();
-            assert!(quiz.get(0).is_ok());
-            assert_eq!(
-                quiz.get(0).unwrap().question,
-                String::from("What color is the sky?")
-            );
+        /// Returns whether `who` owns the quiz
+        #[ink(message)]
+        pub fn is_owner(&self, who: AccountId) -> bool {
+            who == self.owner
         }
 
-        /// We test if providing the correct answer works.
-        #[ink::test]
-        fn correct_answer_works() {
-            let answer = String::from("Blue");
-            let mut quiz = Quiz::new();
-            quiz.add_question(String::from("What color is the sky?"), answer.clone())
-                .unwrap();
-            assert!(quiz.check_answer(0, answer.clone()).is_ok());
-            assert_eq!(quiz.check_answer(0, answer).unwrap(), true);
+        /// Returns whether `who` has educator rights, which the owner always has
+        #[ink(message)]
+        pub fn is_educator(&self, who: AccountId) -> bool {
+            self.actors.get(who) == Some(PowerLevel::Educator)
         }
 
-        /// We test if the wrong answer should fail.
-        #[ink::test]
-        fn wrong_answer_should_fail() {
-            let wrong_answer = String::from("Green XD");
-            let mut quiz = Quiz::new();
-            quiz.add_question(String::from("What color is the sky?"), String::from("Blue"))
-                .unwrap();
-            assert!(quiz.get(0).is_ok());
-            assert!(quiz.check_answer(0, wrong_answer).is_err());
+        /// Returns the version of the deployed contract
+        #[ink(message)]
+        pub fn version(&self) -> u16 {
+            VERSION
         }
-    }
 
-    /// This is how you'd write end-to-end (E2E) or integration tests for ink! contracts.
-    ///
-    /// When running these you need to make sure that you:
-    /// - Compile the tests with the `e2e-tests` feature flag enabled (`--features e2e-tests`)
-    /// - Are running a Substrate node which contains `pallet-contracts` in the background
-    #[cfg(all(test, feature = "e2e-tests"))]
-    mod e2e_tests {}
+        /// Returns the name of the quiz
+        #[ink(message)]
+        pub fn title(&self) -> String {
+            self.title.clone()
+        }
+
+        /// Renames the quiz
+        #[ink(message)]
+        pub fn set_title(&mut self, title: String) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.title = title;
+            Ok(())
+        }
+
+        /// Returns the power level of an account, or `None` if it isn't registered
+        #[ink(message)]
+        pub fn power_level_of(&self, who: AccountId) -> Option<PowerLevel> {
+            self.actors.get(who)
+        }
+
+        /// Adds the transferred value to the reward pool
+        #[ink(message, payable)]
+        pub fn fund(&mut self) {}
+
+        /// Sets the reward paid to the first correct answerer of each question
+        #[ink(message)]
+        pub fn set_reward_per_question(&mut self, amount: Balance) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.reward_per_question = amount;
+            Ok(())
+        }
+
+        /// Sets the points questions are worth when added without explicit points
+        #[ink(message)]
+        pub fn set_default_points(&mut self, points: u32) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.default_points = points;
+            Ok(())
+        }
+
+        /// Sets how many blocks a player must wait between attempts at a question
+        #[ink(message)]
+        pub fn set_cooldown_blocks(&mut self, blocks: BlockNumber) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.cooldown_blocks = blocks;
+            Ok(())
+        }
+
+        /// Only accepts scored answers from block `open_block` through `close_block`
+        #[ink(message)]
+        pub fn set_window(
+            &mut self,
+            open_block: BlockNumber,
+            close_block: BlockNumber,
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if open_block > close_block {
+                return Err(Error::InvalidWindow);
+            }
+            self.window = Some((open_block, close_block));
+            Ok(())
+        }
+
+        /// Accepts scored answers at any block again
+        #[ink(message)]
+        pub fn clear_window(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.window = None;
+            Ok(())
+        }
+
+        /// Sets the least value players must transfer with each answer
+        #[ink(message)]
+        pub fn set_answer_fee(&mut self, fee: Balance) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.answer_fee = fee;
+            Ok(())
+        }
+
+        /// Pays rewards in the given PSP22 token, or in native tokens if `None`
+        #[ink(message)]
+        pub fn set_reward_token(&mut self, token: Option<AccountId>) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.reward_token = token;
+            Ok(())
+        }
+
+        /// Sends `amount` of the reward pool back to the owner once the session has ended
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if self.session_active {
+                return Err(Error::SessionActive);
+            }
+            if Self::env().balance() < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            Self::env()
+                .transfer(self.owner, amount)
+                .map_err(|_| Error::TransferFailed)
+        }
+
+        /// Publishes the plaintext answers to questions once the session has ended.
+        ///
+        /// Every answer is checked against its stored hash before any is published.
+        #[ink(message)]
+        pub fn reveal_answers(&mut self, plaintext: Vec<(u32, String)>) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if self.session_active {
+                return Err(Error::SessionActive);
+            }
+            let mut checked = Vec::new();
+            for (index, answer) in plaintext {
+                let question = self.question(index)?;
+                if !question.answers.contains(&self.hash_answer(
+                    question.id,
+                    &answer,
+                    question.normalize,
+                )) {
+                    return Err(Error::WrongAnswer);
+                }
+                checked.push((question.id, answer));
+            }
+            for (id, answer) in checked {
+                self.revealed.insert(id, &answer);
+            }
+            Ok(())
+        }
+
+        /// Returns the published answer to a question, if it has been revealed
+        #[ink(message)]
+        pub fn revealed_answer(&self, index: u32) -> Option<String> {
+            self.id_of(index).ok().and_then(|id| self.revealed.get(id))
+        }
+
+        /// Settles a question by hand, making `winner` its first solver and paying
+        /// them the reward, for when automatic payout didn't happen
+        #[ink(message)]
+        pub fn force_set_winner(&mut self, index: u32, winner: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            let id = self.id_of(index)?;
+            if self.winners.contains((self.session_id, id)) {
+                return Err(Error::AlreadyClaimed);
+            }
+            self.first_solver.insert(id, &winner);
+            self.winners.insert((self.session_id, id), &winner);
+            if self.reward_per_question > 0 {
+                self.pay_reward(winner)?;
+            }
+            Ok(())
+        }
+
+        /// Wipes every player's score in the current session
+        #[ink(message)]
+        pub fn reset_scores(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            for player in self
+                .session_players
+                .take(self.session_id)
+                .unwrap_or_default()
+            {
+                self.scores.remove((self.session_id, player));
+                Self::env().emit_event(ScoreUpdated {
+                    player,
+                    new_score: 0,
+                });
+            }
+            Ok(())
+        }
+
+        /// Opens a new session, during which scored answers are accepted. Each
+        /// session keeps its own scores.
+        #[ink(message)]
+        pub fn start_session(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.session_id = self.session_id.saturating_add(1);
+            self.session_active = true;
+            self.session_start = Self::env().block_number();
+            Self::env().emit_event(SessionStarted {
+                start_block: self.session_start,
+            });
+            Ok(())
+        }
+
+        /// Closes the current session
+        #[ink(message)]
+        pub fn end_session(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.session_active = false;
+            Self::env().emit_event(SessionEnded {
+                end_block: Self::env().block_number(),
+            });
+            Ok(())
+        }
+
+        /// Makes `index` the question everyone answers through `answer_current`
+        #[ink(message)]
+        pub fn set_current_question(&mut self, index: u32) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let id = self.id_of(index)?;
+            self.current_question = Some(index);
+            self.activated_at.insert(id, &Self::env().block_number());
+            Ok(())
+        }
+
+        /// Freezes answering and adding questions
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.paused = true;
+            Ok(())
+        }
+
+        /// Lifts a previous `pause`
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.paused = false;
+            Ok(())
+        }
+
+        /// Returns whether answering and adding questions is frozen
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Allows or forbids adding questions whose text matches an existing one
+        #[ink(message)]
+        pub fn set_allow_duplicates(&mut self, allow: bool) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.allow_duplicates = allow;
+            Ok(())
+        }
+
+        /// Offers ownership of the quiz to `new_owner`, who must accept it
+        #[ink(message)]
+        pub fn propose_owner(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            self.pending_owner = Some(new_owner);
+            Ok(())
+        }
+
+        /// Completes an ownership transfer started with `propose_owner`
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(Error::InvalidCaller);
+            }
+            self.set_owner(caller);
+            Ok(())
+        }
+
+        /// Transfers ownership of the quiz to `new_owner` in a single call
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_contract_owner(caller)?;
+            if new_owner == AccountId::from([0u8; 32]) {
+                return Err(Error::InvalidCaller);
+            }
+            self.set_owner(new_owner);
+            Ok(())
+        }
+
+        /// Replaces the text, answer and hint of the question at `index`.
+        ///
+        /// Numeric questions keep their tolerance and take `answer` as a whole
+        /// number, failing with `NotNumeric` if it isn't one.
+        #[ink(message)]
+        pub fn edit_question(
+            &mut self,
+            index: u32,
+            question: String,
+            answer: String,
+            hint: Option<String>,
+        ) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            Self::ensure_valid_fields(&question, &[&answer])?;
+            let mut entry = self.question(index)?;
+            if let Some((_, tolerance)) = entry.numeric {
+                let answer = answer.trim().parse().map_err(|_| Error::NotNumeric)?;
+                entry.numeric = Some((answer, tolerance));
+            } else {
+                entry.answers =
+                    ink::prelude::vec![self.hash_answer(entry.id, &answer, entry.normalize)];
+            }
+            entry.question = question;
+            entry.hint = hint;
+            self.questions.insert(index, &entry);
+            Ok(())
+        }
+
+        /// Stops accepting scored answers to a question after block `deadline`
+        #[ink(message)]
+        pub fn set_deadline(&mut self, index: u32, deadline: BlockNumber) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let mut entry = self.question(index)?;
+            entry.deadline = Some(deadline);
+            self.questions.insert(index, &entry);
+            Ok(())
+        }
+
+        /// Changes what a question is worth from now on; points already awarded stay
+        #[ink(message)]
+        pub fn set_points(&mut self, index: u32, points: u32) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let mut entry = self.question(index)?;
+            entry.points = points;
+            self.questions.insert(index, &entry);
+            Ok(())
+        }
+
+        /// Sets how many scored attempts each player may make at a question
+        #[ink(message)]
+        pub fn set_max_attempts(&mut self, index: u32, max_attempts: u8) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let mut entry = self.question(index)?;
+            entry.max_attempts = max_attempts;
+            self.questions.insert(index, &entry);
+            Ok(())
+        }
+
+        /// Hides a question from scoring, or brings it back, keeping its data
+        #[ink(message)]
+        pub fn set_question_enabled(&mut self, index: u32, enabled: bool) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let mut entry = self.question(index)?;
+            entry.enabled = enabled;
+            self.questions.insert(index, &entry);
+            Ok(())
+        }
+
+        /// Removes the question at `index`.
+        ///
+        /// The last question is moved into the freed slot, so question order is
+        /// not preserved: the question previously at the end of the quiz will
+        /// now be found at `index`.
+        #[ink(message)]
+        pub fn remove_question(&mut self, index: u32) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            let removed = self.question(index)?;
+            let last = self.next_index - 1;
+            if index != last {
+                let moved = self.question(last)?;
+                self.questions.insert(index, &moved);
+            }
+            self.questions.remove(last);
+            self.next_index = last;
+            self.forget_question(removed.id);
+            self.current_question = match self.current_question {
+                Some(current) if current == index => None,
+                Some(current) if current == last => Some(index),
+                current => current,
+            };
+            Self::env().emit_event(QuestionRemoved { index });
+            Ok(())
+        }
+
+        /// Removes every question, along with what players did on them.
+        ///
+        /// Per-player records are keyed by question id, and ids are never reused,
+        /// so questions added afterwards start with none.
+        #[ink(message)]
+        pub fn clear_questions(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            for index in 0..self.next_index {
+                let id = self.question(index)?.id;
+                self.questions.remove(index);
+                self.forget_question(id);
+                Self::env().emit_event(QuestionRemoved { index });
+            }
+            self.next_index = 0;
+            self.current_question = None;
+            Ok(())
+        }
+
+        /// Simply returns a question (if it exists)
+        #[ink(message)]
+        pub fn get(&self, index: u32) -> Result<QuestionView, Error> {
+            self.question(index).map(QuestionView::from)
+        }
+
+        /// Returns the questions at `indices`, in the order given, with an error in
+        /// place of each index that doesn't exist
+        #[ink(message)]
+        pub fn get_many(&self, indices: Vec<u32>) -> Vec<Result<QuestionView, Error>> {
+            indices.into_iter().map(|index| self.get(index)).collect()
+        }
+
+        /// Returns up to `len` questions starting at `start`, clamped to the
+        /// questions that exist
+        #[ink(message)]
+        pub fn list_questions(&self, start: u32, len: u32) -> Vec<QuestionView> {
+            let end = start.saturating_add(len).min(self.next_index);
+            (start..end)
+                .filter_map(|index| self.questions.get(index))
+                .map(QuestionView::from)
+                .collect()
+        }
+
+        /// Returns every question in index order, for caching off-chain.
+        ///
+        /// Meant for small quizzes; use `list_questions` to page through large ones.
+        #[ink(message)]
+        pub fn export_questions(&self) -> Vec<QuestionView> {
+            self.list_questions(0, self.next_index)
+        }
+
+        /// Returns the stored hash of a question's first accepted answer, for
+        /// verifying answers locally. Answers are salted and hashed with the
+        /// question's id, so the hash can't be looked up in a precomputed table
+        /// or matched against other questions. Numeric questions have no hash.
+        #[ink(message)]
+        pub fn answer_hash(&self, index: u32) -> Result<[u8; 32], Error> {
+            self.question(index)?
+                .answers
+                .first()
+                .copied()
+                .ok_or(Error::NoAnswerHash)
+        }
+
+        /// Returns a question's hint, if it has one
+        #[ink(message)]
+        pub fn hint_of(&self, index: u32) -> Result<Option<String>, Error> {
+            Ok(self.question(index)?.hint)
+        }
+
+        /// Returns the URI of a question's image, if it has one
+        #[ink(message)]
+        pub fn image_of(&self, index: u32) -> Result<Option<String>, Error> {
+            Ok(self.question(index)?.image_uri)
+        }
+
+        /// Returns how many blocks are left before a question's deadline, or `None`
+        /// if it has no deadline, doesn't exist or has already expired
+        #[ink(message)]
+        pub fn blocks_remaining(&self, index: u32) -> Option<BlockNumber> {
+            let deadline = self.question(index).ok()?.deadline?;
+            let now = Self::env().block_number();
+            if now > deadline {
+                return None;
+            }
+            Some(deadline.saturating_sub(now))
+        }
+
+        /// Hashes `answer` exactly as the contract does for the question at `index`,
+        /// normalization, salt, question id and hash algorithm included, so
+        /// clients don't have to reimplement the pipeline
+        #[ink(message)]
+        pub fn compute_answer_hash(&self, index: u32, answer: String) -> Result<[u8; 32], Error> {
+            let question = self.question(index)?;
+            Ok(self.hash_answer(question.id, &answer, question.normalize))
+        }
+
+        /// Returns how hard a question is
+        #[ink(message)]
+        pub fn difficulty_of(&self, index: u32) -> Result<u8, Error> {
+            Ok(self.question(index)?.difficulty)
+        }
+
+        /// Returns the sum of all questions' points, saturating at `u32::MAX`
+        #[ink(message)]
+        pub fn total_points(&self) -> u32 {
+            (0..self.next_index)
+                .filter_map(|index| self.questions.get(index))
+                .fold(0u32, |total, question| {
+                    total.saturating_add(question.points)
+                })
+        }
+
+        /// Returns every question filed under exactly `category`, with its index
+        #[ink(message)]
+        pub fn list_by_category(&self, category: String) -> Vec<(u32, QuestionView)> {
+            (0..self.next_index)
+                .filter_map(|index| self.questions.get(index).map(|question| (index, question)))
+                .filter(|(_, question)| question.category == category)
+                .map(|(index, question)| (index, QuestionView::from(question)))
+                .collect()
+        }
+
+        /// Returns a permutation of all question indices.
+        ///
+        /// The order is derived deterministically from `seed` and the current block,
+        /// so every caller in the same block sees the same order for the same seed.
+        #[ink(message)]
+        pub fn shuffled_order(&self, seed: u64) -> Vec<u32> {
+            let mut order: Vec<u32> = (0..self.next_index).collect();
+            let mut state = seed ^ u64::from(Self::env().block_number());
+            // Fisher-Yates
+            for i in (1..order.len()).rev() {
+                let j = (Self::next_random(&mut state) % (i as u64 + 1)) as usize;
+                order.swap(i, j);
+            }
+            order
+        }
+
+        /// Picks a question index with probability proportional to its points.
+        ///
+        /// The pick depends only on `seed`, so anyone can verify it. Returns `None`
+        /// if there are no questions or none is worth any points.
+        #[ink(message)]
+        pub fn pick_weighted(&self, seed: u64) -> Option<u32> {
+            let weights: Vec<(u32, u64)> = (0..self.next_index)
+                .filter_map(|index| {
+                    self.questions
+                        .get(index)
+                        .map(|q| (index, u64::from(q.points)))
+                })
+                .collect();
+            let total: u64 = weights.iter().map(|(_, weight)| weight).sum();
+            if total == 0 {
+                return None;
+            }
+            let mut state = seed;
+            let mut target = Self::next_random(&mut state) % total;
+            for (index, weight) in weights {
+                if target < weight {
+                    return Some(index);
+                }
+                target -= weight;
+            }
+            None
+        }
+
+        /// Returns the number of questions in the quiz
+        #[ink(message)]
+        pub fn question_count(&self) -> u32 {
+            self.next_index
+        }
+
+        /// Check if an answer is correct.
+        ///
+        /// Returns `Ok(false)` for a wrong answer; errors are reserved for
+        /// questions that don't exist. Attempts at numeric questions are parsed
+        /// as integers and checked like `check_numeric`.
+        #[ink(message)]
+        pub fn check_answer(&self, index: u32, attempt: String) -> Result<bool, Error> {
+            self.ensure_not_paused()?;
+            let question = self.question(index)?;
+            if question.numeric.is_some() {
+                return match attempt.trim().parse::<i128>() {
+                    Ok(attempt) => self.check_numeric(index, attempt),
+                    Err(_) => Ok(false),
+                };
+            }
+            let answer_hash = self.hash_answer(question.id, &attempt, question.normalize);
+            Ok(question.answers.contains(&answer_hash))
+        }
+
+        /// Checks an answer and awards the caller the question's points if it is correct.
+        ///
+        /// The caller must transfer at least `answer_fee`, which goes to the reward pool.
+        #[ink(message, payable)]
+        pub fn answer_and_score(&mut self, index: u32, attempt: String) -> Result<bool, Error> {
+            self.ensure_fee_paid()?;
+            let caller = Self::env().caller();
+            self.score(caller, index, attempt)
+        }
+
+        /// Like `answer_and_score`, but returns the points awarded, streak included,
+        /// or `0` for a wrong answer
+        #[ink(message, payable)]
+        pub fn answer_and_score_points(
+            &mut self,
+            index: u32,
+            attempt: String,
+        ) -> Result<u32, Error> {
+            self.ensure_fee_paid()?;
+            let caller = Self::env().caller();
+            self.score_with(caller, index, attempt, None)
+                .map(|awarded| awarded.unwrap_or(0))
+        }
+
+        /// Checks an answer on behalf of `player`, crediting them rather than the
+        /// calling educator. The same one scored attempt rules apply as if
+        /// `player` had answered themselves.
+        #[ink(message)]
+        pub fn answer_for(
+            &mut self,
+            player: AccountId,
+            index: u32,
+            attempt: String,
+        ) -> Result<bool, Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.score(player, index, attempt)
+        }
+
+        /// Scores an answer to whichever question is currently active.
+        ///
+        /// The question's points drop by one for every block since it became
+        /// current, down to `MIN_TIMED_POINTS`, so faster answers score more.
+        #[ink(message, payable)]
+        pub fn answer_current(&mut self, attempt: String) -> Result<bool, Error> {
+            self.ensure_fee_paid()?;
+            let caller = Self::env().caller();
+            let index = self.current_question.ok_or(Error::NoActiveSession)?;
+            let question = self.question(index)?;
+            let max_points = question.points;
+            let elapsed = Self::env()
+                .block_number()
+                .saturating_sub(self.activated_at.get(question.id).unwrap_or(0));
+            let points = max_points
+                .saturating_sub(elapsed)
+                .max(MIN_TIMED_POINTS.min(max_points));
+            self.score_with(caller, index, attempt, Some(points))
+                .map(|awarded| awarded.is_some())
+        }
+
+        /// Stores the caller's commitment to an answer, given as the `Blake2x256`
+        /// hash of the SCALE-encoded `(caller, index, answer, nonce)` tuple.
+        ///
+        /// Binding the caller and question in means a commitment copied from
+        /// another player can't be revealed by anyone else.
+        #[ink(message)]
+        pub fn commit_answer(&mut self, index: u32, commitment: [u8; 32]) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            let id = self.id_of(index)?;
+            self.commitments.insert((caller, id), &commitment);
+            Ok(())
+        }
+
+        /// Reveals a previously committed answer and scores it like `answer_and_score`
+        #[ink(message, payable)]
+        pub fn reveal_answer(
+            &mut self,
+            index: u32,
+            answer: String,
+            nonce: u64,
+        ) -> Result<bool, Error> {
+            self.ensure_fee_paid()?;
+            let caller = Self::env().caller();
+            let id = self.id_of(index)?;
+            let commitment = self
+                .commitments
+                .get((caller, id))
+                .ok_or(Error::NoCommitment)?;
+            if Self::hash::<Blake2x256, _>((caller, index, &answer, nonce)) != commitment {
+                return Err(Error::CommitmentMismatch);
+            }
+            self.commitments.remove((caller, id));
+            self.score(caller, index, answer)
+        }
+
+        /// Returns the score of an account in the current session (zero if it
+        /// hasn't scored)
+        #[ink(message)]
+        pub fn score_of(&self, who: AccountId) -> u32 {
+            self.scores.get((self.session_id, who)).unwrap_or(0)
+        }
+
+        /// Returns how many accounts have registered or scored, in any session.
+        /// Accounts are counted once, however many sessions they took part in.
+        #[ink(message)]
+        pub fn player_count(&self) -> u32 {
+            self.players.len() as u32
+        }
+
+        /// Returns the session currently or most recently running
+        #[ink(message)]
+        pub fn session_id(&self) -> u32 {
+            self.session_id
+        }
+
+        /// Returns how many questions an account answered correctly in a row in
+        /// the current session
+        #[ink(message)]
+        pub fn streak_of(&self, who: AccountId) -> u32 {
+            self.streak.get((self.session_id, who)).unwrap_or(0)
+        }
+
+        /// Returns the score of each account, in the order given
+        #[ink(message)]
+        pub fn scores_of(&self, accounts: Vec<AccountId>) -> Vec<(AccountId, u32)> {
+            accounts
+                .into_iter()
+                .map(|account| (account, self.score_of(account)))
+                .collect()
+        }
+
+        /// Returns whether `who` has made a scored attempt at a question in the
+        /// current session
+        #[ink(message)]
+        pub fn has_answered(&self, who: AccountId, index: u32) -> bool {
+            self.id_of(index)
+                .is_ok_and(|id| self.answered.contains((self.session_id, who, id)))
+        }
+
+        /// Returns the percentage of questions `who` has made a scored attempt at
+        #[ink(message)]
+        pub fn completion_of(&self, who: AccountId) -> u8 {
+            let total = self.question_count();
+            if total == 0 {
+                return 0;
+            }
+            (self.answered_count_of(who).saturating_mul(100) / total) as u8
+        }
+
+        /// Returns the score, streak and answered questions of `who` in one call
+        #[ink(message)]
+        pub fn profile_of(&self, who: AccountId) -> PlayerProfile {
+            PlayerProfile {
+                score: self.score_of(who),
+                streak: self.streak_of(who),
+                answered_count: self.answered_count_of(who),
+            }
+        }
+
+        /// Returns how many players answered a question correctly
+        #[ink(message)]
+        pub fn correct_count_of(&self, index: u32) -> u32 {
+            self.id_of(index)
+                .map_or(0, |id| self.correct_count.get(id).unwrap_or(0))
+        }
+
+        /// Returns the first player to answer a question correctly, if any has
+        #[ink(message)]
+        pub fn first_solver_of(&self, index: u32) -> Option<AccountId> {
+            self.id_of(index)
+                .ok()
+                .and_then(|id| self.first_solver.get(id))
+        }
+
+        /// Returns headline numbers about the quiz in one call
+        #[ink(message)]
+        pub fn stats(&self) -> QuizStats {
+            QuizStats {
+                question_count: self.next_index,
+                player_count: self.player_count(),
+                total_answers: self.total_answers,
+                total_points_awarded: self.total_points_awarded,
+            }
+        }
+
+        /// Returns the `(attempts, correct)` scored answers made at a question
+        #[ink(message)]
+        pub fn question_stats(&self, index: u32) -> Result<(u32, u32), Error> {
+            let id = self.id_of(index)?;
+            Ok((
+                self.attempt_count.get(id).unwrap_or(0),
+                self.correct_count.get(id).unwrap_or(0),
+            ))
+        }
+
+        /// Returns up to `n` players of the current session ranked by descending score.
+        ///
+        /// Ties are broken by ascending `AccountId` so the ordering is deterministic.
+        #[ink(message)]
+        pub fn leaderboard(&self, n: u32) -> Vec<(AccountId, u32)> {
+            self.leaderboard_for(self.session_id, n)
+        }
+
+        /// Returns up to `n` players of session `session_id` ranked like `leaderboard`
+        #[ink(message)]
+        pub fn leaderboard_for(&self, session_id: u32, n: u32) -> Vec<(AccountId, u32)> {
+            let mut board: Vec<(AccountId, u32)> = self
+                .players_in(session_id)
+                .into_iter()
+                .map(|player| {
+                    let score = self.scores.get((session_id, player)).unwrap_or(0);
+                    (player, score)
+                })
+                .collect();
+            board.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            board.truncate(n as usize);
+            board
+        }
+
+        /// Returns the accounts with a score in session `session_id`
+        fn players_in(&self, session_id: u32) -> Vec<AccountId> {
+            self.session_players.get(session_id).unwrap_or_default()
+        }
+
+        /// Counts the questions `who` has made a scored attempt at
+        fn answered_count_of(&self, who: AccountId) -> u32 {
+            (0..self.next_index)
+                .filter(|&index| self.has_answered(who, index))
+                .count() as u32
+        }
+
+        /// Advances a SplitMix64 generator; not suitable where unpredictability matters
+        fn next_random(state: &mut u64) -> u64 {
+            *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = *state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        /// Hashes a value with any supported hashing algos
+        fn hash<S: CryptoHash + HashOutput, T: Encode>(entity: T) -> <S as HashOutput>::Type {
+            let mut hash = <<S as HashOutput>::Type as Default>::default();
+            <S as CryptoHash>::hash(&entity.encode(), &mut hash);
+            hash
+        }
+
+        /// Checks several `(index, attempt)` pairs, returning each result in order
+        #[ink(message)]
+        pub fn check_answers(&self, attempts: Vec<(u32, String)>) -> Vec<Result<bool, Error>> {
+            attempts
+                .into_iter()
+                .map(|(index, attempt)| self.check_answer(index, attempt))
+                .collect()
+        }
+
+        /// Check if the chosen option of a multiple choice question is correct
+        #[ink(message)]
+        pub fn check_choice(&self, index: u32, choice: u8) -> Result<bool, Error> {
+            let question = self.question(index)?;
+            let option = question
+                .options
+                .get(choice as usize)
+                .ok_or(Error::InvalidOption)?;
+            self.check_answer(index, option.clone())
+        }
+
+        /// Check if a numeric attempt is within the question's tolerance
+        #[ink(message)]
+        pub fn check_numeric(&self, index: u32, attempt: i128) -> Result<bool, Error> {
+            self.ensure_not_paused()?;
+            let question = self.question(index)?;
+            let (answer, tolerance) = question.numeric.ok_or(Error::NotNumeric)?;
+            Ok(attempt.abs_diff(answer) <= tolerance)
+        }
+
+        /// Makes `new_owner` the owner, cancelling any pending transfer
+        fn set_owner(&mut self, new_owner: AccountId) {
+            let previous = self.owner;
+            self.pending_owner = None;
+            self.owner = new_owner;
+            // The owner is always an educator, even past `MAX_EDUCATORS`
+            if self.actors.get(new_owner) != Some(PowerLevel::Educator) {
+                self.educator_count = self.educator_count.saturating_add(1);
+            }
+            self.actors.insert(new_owner, &PowerLevel::Educator);
+            Self::env().emit_event(OwnershipTransferred {
+                previous,
+                new: new_owner,
+            });
+        }
+
+        /// Stores a new question on behalf of the calling educator
+        fn insert_question(&mut self, entry: Question) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.ensure_powerlevel(caller, PowerLevel::Educator)?;
+            self.ensure_not_paused()?;
+            self.ensure_unique(&entry.question)?;
+            self.push_question(caller, entry);
+            Ok(())
+        }
+
+        /// Builds an open-text question, hashing its answer
+        fn build_question(
+            &self,
+            question: String,
+            answer: String,
+            points: u32,
+            normalize: NormalizeOpts,
+        ) -> Question {
+            Question {
+                id: self.next_id,
+                question,
+                answers: ink::prelude::vec![self.hash_answer(self.next_id, &answer, normalize)],
+                points,
+                normalize,
+                difficulty: MIN_DIFFICULTY,
+                max_attempts: 1,
+                enabled: true,
+                ..Default::default()
+            }
+        }
+
+        /// Stores a question and announces it to indexers
+        fn push_question(&mut self, educator: AccountId, question: Question) {
+            let index = self.next_index;
+            self.questions.insert(index, &question);
+            self.next_index = index + 1;
+            self.next_id = self.next_id.saturating_add(1);
+            Self::env().emit_event(QuestionAdded { index, educator });
+        }
+
+        /// Validates and stores `(question, answer)` pairs worth `default_points`
+        fn push_questions(
+            &mut self,
+            educator: AccountId,
+            items: Vec<(String, String)>,
+        ) -> Result<(), Error> {
+            for (question, answer) in items {
+                Self::ensure_valid_fields(&question, &[&answer])?;
+                self.ensure_unique(&question)?;
+                let entry = self.build_question(
+                    question,
+                    answer,
+                    self.default_points,
+                    NormalizeOpts::default(),
+                );
+                self.push_question(educator, entry);
+            }
+            Ok(())
+        }
+
+        /// Checks an answer and credits `player` with the question's points if it is correct
+        fn score(&mut self, player: AccountId, index: u32, attempt: String) -> Result<bool, Error> {
+            self.score_with(player, index, attempt, None)
+                .map(|awarded| awarded.is_some())
+        }
+
+        /// Checks an answer and credits `player` with `points`, or the question's
+        /// own points if `None`, if it is correct. Returns the points awarded,
+        /// streak included, or `None` for a wrong answer.
+        ///
+        /// Each player gets up to `max_attempts` scored attempts per question, and
+        /// none after answering it correctly. Only the first correct answer the
+        /// reward pool can cover is paid; later ones still score.
+        fn score_with(
+            &mut self,
+            player: AccountId,
+            index: u32,
+            attempt: String,
+            points: Option<u32>,
+        ) -> Result<Option<u32>, Error> {
+            let correct = self.check_answer(index, attempt)?;
+            let question = self.question(index)?;
+            let id = question.id;
+            let key = (self.session_id, player, id);
+            if !self.session_active {
+                return Err(Error::NoActiveSession);
+            }
+            if let Some((open, close)) = self.window {
+                if !(open..=close).contains(&Self::env().block_number()) {
+                    return Err(Error::WindowClosed);
+                }
+            }
+            if !question.enabled {
+                return Err(Error::QuestionDisabled);
+            }
+            if self.answered.get(key) == Some(true) {
+                return Err(Error::AlreadyAnswered);
+            }
+            let attempts = self.attempts.get(key).unwrap_or(0);
+            if attempts >= question.max_attempts {
+                return Err(Error::TooManyAttempts);
+            }
+            let now = Self::env().block_number();
+            if let Some(last) = self.last_attempt.get(key) {
+                if now.saturating_sub(last) < self.cooldown_blocks {
+                    return Err(Error::CooldownActive);
+                }
+            }
+            if let Some(deadline) = question.deadline {
+                if Self::env().block_number() > deadline {
+                    return Err(Error::Expired);
+                }
+            }
+            let rewarded = correct
+                && self.reward_per_question > 0
+                && !self.winners.contains((self.session_id, id))
+                && self.reward_available();
+            self.attempts.insert(key, &(attempts + 1));
+            self.last_attempt.insert(key, &now);
+            self.total_answers = self.total_answers.saturating_add(1);
+            let attempt_count = self.attempt_count.get(id).unwrap_or(0).saturating_add(1);
+            self.attempt_count.insert(id, &attempt_count);
+            self.answered.insert(key, &correct);
+            Self::env().emit_event(AnswerChecked {
+                player,
+                index,
+                correct,
+            });
+            let streak = self.streak_of(player);
+            let awarded = if correct {
+                // Each correct answer in a row multiplies the points by one more
+                let streak = streak.saturating_add(1);
+                let points = points.unwrap_or(question.points).saturating_mul(streak);
+                self.streak.insert((self.session_id, player), &streak);
+                if !self.scores.contains((self.session_id, player)) {
+                    let mut session_players = self.players_in(self.session_id);
+                    session_players.push(player);
+                    self.session_players
+                        .insert(self.session_id, &session_players);
+                }
+                if !self.players.contains(&player) {
+                    self.players.push(player);
+                }
+                let score = self.score_of(player).saturating_add(points);
+                self.scores.insert((self.session_id, player), &score);
+                self.total_points_awarded = self.total_points_awarded.saturating_add(points);
+                Self::env().emit_event(ScoreUpdated {
+                    player,
+                    new_score: score,
+                });
+                // Players may score a question again in a later session, but
+                // only count towards its correct answers once
+                if !self.solved.contains((player, id)) {
+                    self.solved.insert((player, id), &true);
+                    let count = self.correct_count.get(id).unwrap_or(0).saturating_add(1);
+                    self.correct_count.insert(id, &count);
+                }
+                if !self.first_solver.contains(id) {
+                    self.first_solver.insert(id, &player);
+                }
+                Some(points)
+            } else {
+                self.streak.remove((self.session_id, player));
+                None
+            };
+            if rewarded {
+                self.winners.insert((self.session_id, id), &player);
+                self.pay_reward(player)?;
+            }
+            Ok(awarded)
+        }
+
+        /// Pays `reward_per_question` to `winner` in the reward token, or in
+        /// native tokens if none is set
+        fn pay_reward(&self, winner: AccountId) -> Result<(), Error> {
+            match self.reward_token {
+                Some(token) => self.transfer_token(token, winner, self.reward_per_question),
+                None => Self::env()
+                    .transfer(winner, self.reward_per_question)
+                    .map_err(|_| Error::TransferFailed),
+            }
+        }
+
+        /// Returns whether this contract holds enough of the reward token, or of
+        /// native tokens if none is set, to pay `reward_per_question`
+        fn reward_available(&self) -> bool {
+            let balance = match self.reward_token {
+                Some(token) => self.token_balance(token),
+                None => Self::env().balance(),
+            };
+            balance >= self.reward_per_question
+        }
+
+        /// Calls `PSP22::balance_of` on `token` for this contract, treating a
+        /// failed call as an empty balance
+        fn token_balance(&self, token: AccountId) -> Balance {
+            build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::balance_of")))
+                        .push_arg(Self::env().account_id()),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok)
+                .unwrap_or(0)
+        }
+
+        /// Calls `PSP22::transfer` on `token`, moving `value` from this contract to `to`
+        fn transfer_token(
+            &self,
+            token: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), Error> {
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::transfer")))
+                        .push_arg(to)
+                        .push_arg(value)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<Result<(), PSP22Error>>()
+                .try_invoke();
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::RewardTransferFailed),
+            }
+        }
+
+        /// Normalizes an answer with `normalize` and hashes it together with the
+        /// question's `id` and this contract's salt.
+        ///
+        /// Both stored answers and attempts go through here, so they are always
+        /// compared in the same form.
+        fn hash_answer(&self, id: u32, answer: &str, normalize: NormalizeOpts) -> [u8; 32] {
+            let salted = (id, self.salt, normalize_answer(answer, normalize));
+            match self.hash_algo {
+                HashAlgo::Blake2x256 => Self::hash::<Blake2x256, _>(salted),
+                HashAlgo::Keccak256 => Self::hash::<Keccak256, _>(salted),
+                HashAlgo::Sha2x256 => Self::hash::<Sha2x256, _>(salted),
+            }
+        }
+
+        /// Checks that `id` is registered with exactly `level`.
+        ///
+        /// Fails with `NotRegistered` for unknown accounts and `InvalidPowerLevel`
+        /// for registered accounts with another role.
+        fn ensure_powerlevel(&self, id: AccountId, level: PowerLevel) -> Result<(), Error> {
+            match self.actors.get(id) {
+                Some(power_level) if power_level == level => Ok(()),
+                Some(_) => Err(Error::InvalidPowerLevel),
+                None => Err(Error::NotRegistered),
+            }
+        }
+
+        fn ensure_contract_owner(&self, id: AccountId) -> Result<(), Error> {
+            if id != self.owner {
+                return Err(Error::InvalidCaller);
+            }
+            Ok(())
+        }
+
+        /// Checks that a question and its answers are neither blank nor too long
+        fn ensure_valid_fields(question: &str, answers: &[&str]) -> Result<(), Error> {
+            if question.trim().is_empty() || answers.iter().any(|answer| answer.trim().is_empty()) {
+                return Err(Error::EmptyField);
+            }
+            // Lengths are in bytes, which is what storage is paid for
+            if question.len() > MAX_QUESTION_LEN
+                || answers.iter().any(|answer| answer.len() > MAX_ANSWER_LEN)
+            {
+                return Err(Error::TooLong);
+            }
+            Ok(())
+        }
+
+        /// Returns the id of the question at `index`
+        fn id_of(&self, index: u32) -> Result<u32, Error> {
+            self.question(index).map(|question| question.id)
+        }
+
+        /// Drops the per-question records kept under a removed question's id.
+        /// Per-player and per-session records stay behind, but ids are never
+        /// reused so nothing reads them again.
+        fn forget_question(&mut self, id: u32) {
+            self.correct_count.remove(id);
+            self.attempt_count.remove(id);
+            self.first_solver.remove(id);
+            self.revealed.remove(id);
+            self.activated_at.remove(id);
+        }
+
+        /// Loads the question at `index`, which must be below `next_index`
+        fn question(&self, index: u32) -> Result<Question, Error> {
+            // Checked up front rather than trusting the mapping to be empty past the end
+            if index >= self.next_index {
+                return Err(Error::QuestionDoesntExist);
+            }
+            self.questions.get(index).ok_or(Error::QuestionDoesntExist)
+        }
+
+        fn ensure_unique(&self, question: &str) -> Result<(), Error> {
+            if self.allow_duplicates {
+                return Ok(());
+            }
+            let question = question.to_lowercase();
+            let duplicate = (0..self.next_index)
+                .filter_map(|index| self.questions.get(index))
+                .any(|existing| existing.question.to_lowercase() == question);
+            if duplicate {
+                return Err(Error::DuplicateQuestion);
+            }
+            Ok(())
+        }
+
+        fn ensure_fee_paid(&self) -> Result<(), Error> {
+            if Self::env().transferred_value() < self.answer_fee {
+                return Err(Error::FeeTooLow);
+            }
+            Ok(())
+        }
+
+        fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.paused {
+                return Err(Error::Paused);
+            }
+            Ok(())
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        type Event = <Quiz as ::ink::reflect::ContractEventBase>::Type;
+
+        /// Decodes every event emitted so far in the test environment.
+        fn recorded_events() -> Vec<Event> {
+            ink::env::test::recorded_events()
+                .map(|event| <Event as scale::Decode>::decode(&mut &event.data[..]).unwrap())
+                .collect()
+        }
+
+        /// We test if the default constructor does its job.
+        #[ink::test]
+        fn default_works() {
+            let quiz = Quiz::new();
+            assert!(quiz.get(0).is_err());
+        }
+
+        /// We test if creating a new contract with questions works.
+        #[ink::test]
+        fn add_questions() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert!(quiz.get(0).is_ok());
+            assert_eq!(
+                quiz.get(0).unwrap().question,
+                String::from("What color is the sky?")
+            );
+        }
+
+        /// We test if providing the correct answer works.
+        #[ink::test]
+        fn correct_answer_works() {
+            let answer = String::from("Blue");
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), answer.clone())
+                .unwrap();
+            assert_eq!(quiz.check_answer(0, answer), Ok(true));
+        }
+
+        /// We test if the wrong answer is reported as incorrect.
+        #[ink::test]
+        fn wrong_answer_should_fail() {
+            let wrong_answer = String::from("Green XD");
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert!(quiz.get(0).is_ok());
+            assert_eq!(quiz.check_answer(0, wrong_answer), Ok(false));
+        }
+
+        /// We test if the owner can grant educator rights to another account.
+        #[ink::test]
+        fn owner_can_add_educator() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.add_educator(accounts.bob), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    String::from("Blue")
+                ),
+                Ok(())
+            );
+        }
+
+        /// We test if the owner can't revoke their own educator rights.
+        #[ink::test]
+        fn owner_cannot_remove_self_as_educator() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.remove_educator(accounts.alice),
+                Err(Error::InvalidPowerLevel)
+            );
+            assert_eq!(quiz.educator_count, 1);
+            assert!(quiz
+                .add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .is_ok());
+        }
+
+        /// We test if a removed educator can no longer add questions.
+        #[ink::test]
+        fn removed_educator_cannot_add_question() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_educator(accounts.bob).unwrap();
+            assert_eq!(quiz.remove_educator(accounts.bob), Ok(()));
+            assert_eq!(
+                quiz.remove_educator(accounts.bob),
+                Err(Error::InvalidPowerLevel)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(quiz
+                .add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .is_err());
+        }
+
+        /// We test if the question count tracks added questions.
+        #[ink::test]
+        fn question_count_works() {
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.question_count(), 0);
+            for i in 0..3 {
+                quiz.add_question_default(format!("Q{}", i), format!("A{}", i))
+                    .unwrap();
+            }
+            assert_eq!(quiz.question_count(), 3);
+        }
+
+        /// We test if editing a question replaces both its text and answer.
+        #[ink::test]
+        fn edit_question_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Bleu"))
+                .unwrap();
+            assert_eq!(
+                quiz.edit_question(
+                    0,
+                    String::from("What colour is the sky?"),
+                    String::from("Blue"),
+                    None,
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                quiz.get(0).unwrap().question,
+                String::from("What colour is the sky?")
+            );
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("Bleu")), Ok(false));
+            assert_eq!(
+                quiz.edit_question(1, String::from("?"), String::from("!"), None),
+                Err(Error::QuestionDoesntExist)
+            );
+        }
+
+        /// We test if several questions can be added in one call.
+        #[ink::test]
+        fn add_questions_batch_works() {
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.add_questions(Vec::new()), Ok(()));
+            assert_eq!(quiz.question_count(), 0);
+            let items = (0..5)
+                .map(|i| (format!("Q{}", i), format!("A{}", i)))
+                .collect();
+            assert_eq!(quiz.add_questions(items), Ok(()));
+            assert_eq!(quiz.question_count(), 5);
+            for i in 0..5 {
+                assert_eq!(quiz.get(i).unwrap().question, format!("Q{}", i));
+                assert_eq!(quiz.check_answer(i, format!("A{}", i)), Ok(true));
+            }
+        }
+
+        /// Builds a quiz holding the questions "Q0", "Q1" and "Q2".
+        fn quiz_with_three_questions() -> Quiz {
+            let mut quiz = Quiz::new();
+            for i in 0..3 {
+                quiz.add_question_default(format!("Q{}", i), format!("A{}", i))
+                    .unwrap();
+            }
+            quiz
+        }
+
+        /// We test a question moved by `remove_question` keeps its records and can
+        /// still be scored at its new index.
+        #[ink::test]
+        fn remove_middle_question_keeps_moved_records() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            quiz.answer_and_score(2, String::from("A2")).unwrap();
+            quiz.set_current_question(2).unwrap();
+            assert_eq!(quiz.remove_question(1), Ok(()));
+
+            assert_eq!(quiz.get(1).unwrap().question, String::from("Q2"));
+            assert_eq!(quiz.correct_count_of(1), 1);
+            assert_eq!(quiz.first_solver_of(1), Some(accounts.alice));
+            assert!(quiz.has_answered(accounts.alice, 1));
+            assert_eq!(quiz.correct_count_of(2), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.answer_current(String::from("A2")), Ok(true));
+            assert_eq!(quiz.correct_count_of(1), 2);
+            assert_eq!(quiz.question_stats(1), Ok((2, 2)));
+        }
+
+        /// We test removing the current question leaves no question current.
+        #[ink::test]
+        fn remove_current_question_clears_it() {
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.set_current_question(1).unwrap();
+            quiz.remove_question(1).unwrap();
+            assert_eq!(
+                quiz.answer_current(String::from("A2")),
+                Err(Error::NoActiveSession)
+            );
+        }
+
+        /// We test removing the first question moves the last one into its place.
+        #[ink::test]
+        fn remove_first_question() {
+            let mut quiz = quiz_with_three_questions();
+            assert_eq!(quiz.remove_question(0), Ok(()));
+            assert_eq!(quiz.question_count(), 2);
+            assert_eq!(quiz.get(0).unwrap().question, String::from("Q2"));
+            assert_eq!(quiz.get(1).unwrap().question, String::from("Q1"));
+        }
+
+        /// We test removing a middle question moves the last one into its place.
+        #[ink::test]
+        fn remove_middle_question() {
+            let mut quiz = quiz_with_three_questions();
+            assert_eq!(quiz.remove_question(1), Ok(()));
+            assert_eq!(quiz.question_count(), 2);
+            assert_eq!(quiz.get(0).unwrap().question, String::from("Q0"));
+            assert_eq!(quiz.get(1).unwrap().question, String::from("Q2"));
+            assert_eq!(quiz.check_answer(1, String::from("A2")), Ok(true));
+        }
+
+        /// We test removing the last question leaves the others untouched.
+        #[ink::test]
+        fn remove_last_question() {
+            let mut quiz = quiz_with_three_questions();
+            assert_eq!(quiz.remove_question(2), Ok(()));
+            assert_eq!(quiz.question_count(), 2);
+            assert_eq!(quiz.get(0).unwrap().question, String::from("Q0"));
+            assert_eq!(quiz.get(1).unwrap().question, String::from("Q1"));
+            assert_eq!(quiz.get(2).unwrap_err(), Error::QuestionDoesntExist);
+            assert_eq!(quiz.remove_question(2), Err(Error::QuestionDoesntExist));
+        }
+
+        /// We test if only correct answers increase the caller's score.
+        #[ink::test]
+        fn answer_and_score_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.add_question_default(String::from("What color is grass?"), String::from("Green"))
+                .unwrap();
+            assert_eq!(quiz.score_of(accounts.alice), 0);
+            assert_eq!(quiz.answer_and_score(0, String::from("Green")), Ok(false));
+            assert_eq!(quiz.score_of(accounts.alice), 0);
+            assert_eq!(quiz.answer_and_score(1, String::from("Green")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            assert_eq!(
+                quiz.answer_and_score(2, String::from("Blue")),
+                Err(Error::QuestionDoesntExist)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            assert_eq!(quiz.score_of(accounts.bob), 0);
+        }
+
+        /// We test if a question awards its configured points.
+        #[ink::test]
+        fn question_points_are_awarded() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question(
+                String::from("What color is the sky?"),
+                String::from("Blue"),
+                Some(5),
+            )
+            .unwrap();
+            assert_eq!(quiz.get(0).unwrap().points, 5);
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 5);
+        }
+
+        /// We test if the leaderboard ranks players by score.
+        #[ink::test]
+        fn leaderboard_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question(String::from("Q0"), String::from("A0"), Some(1))
+                .unwrap();
+            quiz.add_question(String::from("Q1"), String::from("A1"), Some(2))
+                .unwrap();
+            quiz.add_question(String::from("Q2"), String::from("A2"), Some(4))
+                .unwrap();
+            for (player, indices) in [
+                (accounts.bob, vec![0]),
+                (accounts.charlie, vec![0, 1, 2]),
+                (accounts.django, vec![1, 2]),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(player);
+                for i in indices {
+                    quiz.answer_and_score(i, format!("A{}", i)).unwrap();
+                }
+            }
+            assert_eq!(
+                quiz.leaderboard(10),
+                vec![
+                    (accounts.charlie, 17),
+                    (accounts.django, 10),
+                    (accounts.bob, 1)
+                ]
+            );
+            assert_eq!(quiz.leaderboard(1), vec![(accounts.charlie, 17)]);
+            assert_eq!(quiz.leaderboard(0), vec![]);
+        }
+
+        /// We test if contracts with different salts store different hashes.
+        #[ink::test]
+        fn answers_are_salted() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut stored = Vec::new();
+            // Each contract needs its own address, as that's what storage is keyed by
+            for callee in [accounts.charlie, accounts.django] {
+                ink::env::test::set_callee::<ink::env::DefaultEnvironment>(callee);
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                let mut quiz = Quiz::new();
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    String::from("Blue"),
+                )
+                .unwrap();
+                assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+                stored.push((quiz.salt, quiz.answer_hash(0).unwrap()));
+            }
+            assert_ne!(stored[0].0, stored[1].0);
+            assert_ne!(stored[0].1, stored[1].1);
+        }
+
+        /// Builds the commitment `who` would submit for `answer` and `nonce` to the
+        /// question at `index`.
+        fn commitment(who: AccountId, index: u32, answer: &str, nonce: u64) -> [u8; 32] {
+            Quiz::hash::<Blake2x256, _>((who, index, String::from(answer), nonce))
+        }
+
+        /// We test if revealing a matching commitment scores the answer.
+        #[ink::test]
+        fn commit_reveal_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.commit_answer(0, commitment(accounts.alice, 0, "Blue", 42)),
+                Ok(())
+            );
+            assert_eq!(quiz.reveal_answer(0, String::from("Blue"), 42), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+        }
+
+        /// We test if a commitment copied from another player can't be revealed.
+        #[ink::test]
+        fn copied_commitment_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            let victim = commitment(accounts.alice, 0, "Blue", 42);
+            quiz.commit_answer(0, victim).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.commit_answer(0, victim).unwrap();
+            assert_eq!(
+                quiz.reveal_answer(0, String::from("Blue"), 42),
+                Err(Error::CommitmentMismatch)
+            );
+            assert_eq!(quiz.score_of(accounts.bob), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.reveal_answer(0, String::from("Blue"), 42), Ok(true));
+        }
+
+        /// We test if a reveal that doesn't match the commitment is rejected.
+        #[ink::test]
+        fn mismatched_reveal_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.commit_answer(0, commitment(accounts.alice, 0, "Green", 42))
+                .unwrap();
+            assert_eq!(
+                quiz.reveal_answer(0, String::from("Blue"), 42),
+                Err(Error::CommitmentMismatch)
+            );
+            assert_eq!(
+                quiz.reveal_answer(0, String::from("Green"), 7),
+                Err(Error::CommitmentMismatch)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 0);
+        }
+
+        /// We test if revealing without a prior commitment is rejected.
+        #[ink::test]
+        fn reveal_without_commit_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.reveal_answer(0, String::from("Blue"), 42),
+                Err(Error::NoCommitment)
+            );
+            assert_eq!(
+                quiz.commit_answer(1, commitment(accounts.alice, 1, "Blue", 42)),
+                Err(Error::QuestionDoesntExist)
+            );
+        }
+
+        /// We test if a player can't score the same question twice.
+        #[ink::test]
+        fn second_scoring_attempt_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("Blue")),
+                Err(Error::AlreadyAnswered)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            // Checking stays unrestricted
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+        }
+
+        /// We test if a proposed owner can accept ownership.
+        #[ink::test]
+        fn two_step_ownership_transfer_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.propose_owner(accounts.bob), Ok(()));
+            assert_eq!(quiz.owner, accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.accept_ownership(), Ok(()));
+            assert_eq!(quiz.owner, accounts.bob);
+            assert_eq!(quiz.pending_owner, None);
+            assert_eq!(quiz.add_educator(accounts.charlie), Ok(()));
+        }
+
+        /// We test if only the proposed owner can accept ownership.
+        #[ink::test]
+        fn unauthorized_accept_ownership_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.accept_ownership(), Err(Error::InvalidCaller));
+            quiz.propose_owner(accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(quiz.accept_ownership(), Err(Error::InvalidCaller));
+            assert_eq!(
+                quiz.propose_owner(accounts.charlie),
+                Err(Error::InvalidCaller)
+            );
+            assert_eq!(quiz.owner, accounts.alice);
+        }
+
+        /// We test if pausing blocks writes and answers but not reads.
+        #[ink::test]
+        fn pause_blocks_writes() {
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.pause(), Ok(()));
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is grass?"),
+                    String::from("Green")
+                ),
+                Err(Error::Paused)
+            );
+            assert_eq!(
+                quiz.check_answer(0, String::from("Blue")),
+                Err(Error::Paused)
+            );
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("Blue")),
+                Err(Error::Paused)
+            );
+            assert!(quiz.get(0).is_ok());
+            assert_eq!(quiz.question_count(), 1);
+        }
+
+        /// We test if unpausing restores writes and answers.
+        #[ink::test]
+        fn unpause_restores_writes() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.pause().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.unpause(), Err(Error::InvalidCaller));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.unpause(), Ok(()));
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is grass?"),
+                    String::from("Green")
+                ),
+                Ok(())
+            );
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+        }
+
+        /// We test if adding a question emits a `QuestionAdded` event.
+        #[ink::test]
+        fn add_question_emits_event() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            let Event::QuestionAdded(QuestionAdded { index, educator }) = &events[0] else {
+                panic!("expected QuestionAdded");
+            };
+            assert_eq!(*index, 0);
+            assert_eq!(*educator, accounts.alice);
+        }
+
+        /// We test if scored attempts emit `AnswerChecked` with the outcome.
+        #[ink::test]
+        fn scoring_emits_answer_checked() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.add_question_default(String::from("What color is grass?"), String::from("Green"))
+                .unwrap();
+            quiz.answer_and_score(0, String::from("Blue")).unwrap();
+            quiz.answer_and_score(1, String::from("Blue")).unwrap();
+            let checked: Vec<(AccountId, u32, bool)> = recorded_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::AnswerChecked(AnswerChecked {
+                        player,
+                        index,
+                        correct,
+                    }) => Some((player, index, correct)),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                checked,
+                vec![(accounts.alice, 0, true), (accounts.alice, 1, false)]
+            );
+        }
+
+        /// We test if accepting ownership emits `OwnershipTransferred`.
+        #[ink::test]
+        fn ownership_transfer_emits_event() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.propose_owner(accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.accept_ownership().unwrap();
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            let Event::OwnershipTransferred(OwnershipTransferred { previous, new }) = &events[0]
+            else {
+                panic!("expected OwnershipTransferred");
+            };
+            assert_eq!(*previous, accounts.alice);
+            assert_eq!(*new, accounts.bob);
+        }
+
+        /// Builds a quiz holding a single multiple choice question.
+        fn quiz_with_multiple_choice() -> Quiz {
+            let mut quiz = Quiz::new();
+            quiz.add_multiple_choice(
+                String::from("What color is the sky?"),
+                vec![
+                    String::from("Green"),
+                    String::from("Blue"),
+                    String::from("Red"),
+                ],
+                1,
+            )
+            .unwrap();
+            quiz
+        }
+
+        /// We test if choosing the correct option works.
+        #[ink::test]
+        fn correct_choice_works() {
+            let quiz = quiz_with_multiple_choice();
+            assert_eq!(quiz.get(0).unwrap().options.len(), 3);
+            assert_eq!(quiz.check_choice(0, 1), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+        }
+
+        /// We test if choosing a wrong or invalid option fails.
+        #[ink::test]
+        fn wrong_choice_fails() {
+            let mut quiz = quiz_with_multiple_choice();
+            assert_eq!(quiz.check_choice(0, 0), Ok(false));
+            assert_eq!(quiz.check_choice(0, 2), Ok(false));
+            assert_eq!(quiz.check_choice(0, 3), Err(Error::InvalidOption));
+            assert_eq!(quiz.check_choice(1, 0), Err(Error::QuestionDoesntExist));
+            assert_eq!(
+                quiz.add_multiple_choice(String::from("?"), vec![String::from("A")], 1),
+                Err(Error::InvalidOption)
+            );
+        }
+
+        /// We test if a case-insensitive question ignores letter case.
+        #[ink::test]
+        fn case_insensitive_answer_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_case_insensitive_question(
+                String::from("What color is the sky?"),
+                String::from("Blue"),
+                1,
+            )
+            .unwrap();
+            for attempt in ["Blue", "blue", "BLUE"] {
+                assert_eq!(quiz.check_answer(0, String::from(attempt)), Ok(true));
+            }
+            assert_eq!(quiz.check_answer(0, String::from("Green")), Ok(false));
+        }
+
+        /// We test if a case-sensitive question rejects mismatched casing.
+        #[ink::test]
+        fn case_sensitive_answer_rejects_casing() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("blue")), Ok(false));
+            assert_eq!(quiz.check_answer(0, String::from("BLUE")), Ok(false));
+        }
+
+        /// We test if surrounding whitespace is ignored when checking answers.
+        #[ink::test]
+        fn answer_whitespace_is_trimmed() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.check_answer(0, String::from("  Blue  ")), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("\tBlue\n")), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("B lue")), Ok(false));
+        }
+
+        /// We test if the seeding constructor stores the provided questions.
+        #[ink::test]
+        fn new_with_questions_works() {
+            let quiz = Quiz::new_with_questions(vec![
+                (String::from("What color is the sky?"), String::from("Blue")),
+                (String::from("What color is grass?"), String::from("Green")),
+            ])
+            .unwrap();
+            assert_eq!(quiz.question_count(), 2);
+            assert_eq!(
+                quiz.get(1).unwrap().question,
+                String::from("What color is grass?")
+            );
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+            assert_eq!(
+                Quiz::new_with_questions(Vec::new())
+                    .unwrap()
+                    .question_count(),
+                0
+            );
+        }
+
+        /// We test if the seeding constructor rejects questions `add_questions` would.
+        #[ink::test]
+        fn new_with_questions_validates() {
+            assert_eq!(
+                Quiz::new_with_questions(vec![(String::from("Q0"), String::new())]).err(),
+                Some(Error::EmptyField)
+            );
+            assert_eq!(
+                Quiz::new_with_questions(vec![
+                    (String::from("Q0"), String::from("A0")),
+                    (String::from("q0"), String::from("A1")),
+                ])
+                .err(),
+                Some(Error::DuplicateQuestion)
+            );
+        }
+
+        /// We test if listing a page of questions works.
+        #[ink::test]
+        fn list_questions_page() {
+            let quiz = quiz_with_three_questions();
+            let page = quiz.list_questions(1, 2);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page[0].question, String::from("Q1"));
+            assert_eq!(page[1].question, String::from("Q2"));
+        }
+
+        /// We test if a page running off the end is truncated.
+        #[ink::test]
+        fn list_questions_runs_off_end() {
+            let quiz = quiz_with_three_questions();
+            let page = quiz.list_questions(2, u32::MAX);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].question, String::from("Q2"));
+        }
+
+        /// We test if a start past the end yields an empty page.
+        #[ink::test]
+        fn list_questions_out_of_range_start() {
+            let quiz = quiz_with_three_questions();
+            assert!(quiz.list_questions(3, 5).is_empty());
+            assert!(quiz.list_questions(u32::MAX, 5).is_empty());
+        }
+
+        /// We test if power levels can be queried.
+        #[ink::test]
+        fn power_level_of_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let quiz = Quiz::new();
+            assert_eq!(
+                quiz.power_level_of(accounts.alice),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(quiz.power_level_of(accounts.eve), None);
+        }
+
+        /// We test if an unregistered account can register as a user.
+        #[ink::test]
+        fn register_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.register(), Ok(()));
+            assert_eq!(quiz.power_level_of(accounts.bob), Some(PowerLevel::User));
+        }
+
+        /// We test if registering twice, or as an educator, is rejected.
+        #[ink::test]
+        fn double_register_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.register(), Err(Error::InvalidPowerLevel));
+            assert_eq!(
+                quiz.power_level_of(accounts.alice),
+                Some(PowerLevel::Educator)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.register().unwrap();
+            assert_eq!(quiz.register(), Err(Error::InvalidPowerLevel));
+        }
+
+        /// We test if scored answers are rejected once the deadline passes.
+        #[ink::test]
+        fn answers_after_deadline_are_rejected() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.add_question_default(String::from("What color is grass?"), String::from("Green"))
+                .unwrap();
+            let deadline = ink::env::block_number::<ink::env::DefaultEnvironment>() + 1;
+            assert_eq!(quiz.set_deadline(0, deadline), Ok(()));
+            assert_eq!(quiz.set_deadline(1, deadline), Ok(()));
+            assert_eq!(
+                quiz.set_deadline(2, deadline),
+                Err(Error::QuestionDoesntExist)
+            );
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                quiz.answer_and_score(1, String::from("Green")),
+                Err(Error::Expired)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+        }
+
+        /// We test if correct answers are counted once per player.
+        #[ink::test]
+        fn correct_count_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.correct_count_of(0), 0);
+            for player in [accounts.bob, accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(player);
+                quiz.answer_and_score(0, String::from("Blue")).unwrap();
+                assert!(quiz.answer_and_score(0, String::from("Blue")).is_err());
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(0, String::from("Green")).unwrap();
+            assert_eq!(quiz.correct_count_of(0), 2);
+        }
+
+        /// Builds a quiz living at `frank`'s address, with a funded reward pool
+        /// of 100 and a reward of 10 per question.
+        fn funded_quiz() -> Quiz {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.frank);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.frank, 0);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                accounts.alice,
+                1000,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.set_reward_per_question(10).unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::transfer_in::<ink::env::DefaultEnvironment>(100);
+            quiz.fund();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            quiz
+        }
+
+        /// Returns the native balance of an account.
+        fn balance_of(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account).unwrap()
+        }
+
+        /// We test if funding adds to the contract's balance.
+        #[ink::test]
+        fn fund_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let _quiz = funded_quiz();
+            assert_eq!(balance_of(accounts.frank), 100);
+        }
+
+        /// We test if the first correct answerer is paid the reward.
+        #[ink::test]
+        fn first_correct_answer_is_rewarded() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.bob, 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            assert_eq!(balance_of(accounts.bob), 10);
+            assert_eq!(quiz.winners.get((quiz.session_id, 0)), Some(accounts.bob));
+        }
+
+        /// We test if a second correct answerer scores without being paid again.
+        #[ink::test]
+        fn second_correct_answer_is_not_rewarded() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("Blue")).unwrap();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                accounts.charlie,
+                0,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.charlie), 1);
+            assert_eq!(balance_of(accounts.charlie), 0);
+            assert_eq!(balance_of(accounts.frank), 90);
+        }
+
+        /// We test if a correct answer still scores when the pool can't pay the reward.
+        #[ink::test]
+        fn empty_pool_skips_reward() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.frank, 5);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.bob, 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.answer_and_score(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.bob), 1);
+            assert_eq!(balance_of(accounts.bob), 0);
+            assert_eq!(quiz.winners.get((quiz.session_id, 0)), None);
+        }
+
+        /// Builds a quiz holding a numeric question with answer 314 and tolerance 2.
+        fn quiz_with_numeric_question() -> Quiz {
+            let mut quiz = Quiz::new();
+            quiz.add_numeric_question(String::from("What is pi times 100?"), 314, 2)
+                .unwrap();
+            quiz
+        }
+
+        /// We test if an exact numeric answer is accepted.
+        #[ink::test]
+        fn numeric_exact_match_works() {
+            let quiz = quiz_with_numeric_question();
+            assert_eq!(quiz.check_numeric(0, 314), Ok(true));
+        }
+
+        /// We test if numeric answers within tolerance are accepted.
+        #[ink::test]
+        fn numeric_within_tolerance_works() {
+            let quiz = quiz_with_numeric_question();
+            assert_eq!(quiz.check_numeric(0, 312), Ok(true));
+            assert_eq!(quiz.check_numeric(0, 316), Ok(true));
+        }
+
+        /// We test if numeric answers outside the tolerance are rejected.
+        #[ink::test]
+        fn numeric_out_of_tolerance_fails() {
+            let mut quiz = quiz_with_numeric_question();
+            assert_eq!(quiz.check_numeric(0, 311), Ok(false));
+            assert_eq!(quiz.check_numeric(0, 317), Ok(false));
+            assert_eq!(quiz.check_numeric(0, i128::MIN), Ok(false));
+            assert_eq!(quiz.check_numeric(1, 314), Err(Error::QuestionDoesntExist));
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.check_numeric(1, 314), Err(Error::NotNumeric));
+        }
+
+        /// We test if editing a numeric question replaces its numeric answer.
+        #[ink::test]
+        fn edit_numeric_question_works() {
+            let mut quiz = quiz_with_numeric_question();
+            assert_eq!(
+                quiz.edit_question(0, String::from("What is 7?"), String::from("seven"), None),
+                Err(Error::NotNumeric)
+            );
+            assert_eq!(
+                quiz.edit_question(0, String::from("What is 7?"), String::from("7"), None),
+                Ok(())
+            );
+            assert_eq!(quiz.check_answer(0, String::from("7")), Ok(true));
+            assert_eq!(quiz.check_numeric(0, 9), Ok(true));
+            assert_eq!(quiz.check_answer(0, String::from("314")), Ok(false));
+        }
+
+        /// We test if numeric questions can be answered and scored as text.
+        #[ink::test]
+        fn numeric_answer_and_score_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_numeric_question();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.check_answer(0, String::from("pi")), Ok(false));
+            assert_eq!(quiz.answer_and_score(0, String::from(" 315 ")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.answer_and_score(0, String::from("320")), Ok(false));
+        }
+
+        /// We test if the owner can transfer ownership in a single call.
+        #[ink::test]
+        fn transfer_ownership_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.transfer_ownership(accounts.bob), Ok(()));
+            assert_eq!(quiz.owner, accounts.bob);
+            assert_eq!(
+                quiz.power_level_of(accounts.bob),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(
+                quiz.transfer_ownership(accounts.charlie),
+                Err(Error::InvalidCaller)
+            );
+        }
+
+        /// We test if only the owner can transfer ownership.
+        #[ink::test]
+        fn unauthorized_transfer_ownership_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.transfer_ownership(accounts.bob),
+                Err(Error::InvalidCaller)
+            );
+            assert_eq!(quiz.owner, accounts.alice);
+        }
+
+        /// We test if transferring ownership to the zero address is rejected.
+        #[ink::test]
+        fn transfer_ownership_to_zero_address_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.transfer_ownership(AccountId::from([0u8; 32])),
+                Err(Error::InvalidCaller)
+            );
+            assert_eq!(quiz.owner, accounts.alice);
+        }
+
+        /// Describes a question worth `default_points` with no category, hint,
+        /// image or deadline.
+        fn spec(question: &str, answer: &str) -> QuestionSpec {
+            QuestionSpec {
+                question: String::from(question),
+                answer: String::from(answer),
+                points: None,
+                category: String::new(),
+                difficulty: MIN_DIFFICULTY,
+                hint: None,
+                image_uri: None,
+                deadline: None,
+            }
+        }
+
+        /// We test if questions can be filtered by category.
+        #[ink::test]
+        fn list_by_category_works() {
+            let mut quiz = Quiz::new();
+            for (question, category) in [
+                ("What color is the sky?", "Nature"),
+                ("What is 2 + 2?", "Math"),
+                ("What color is grass?", "Nature"),
+            ] {
+                quiz.add_question_full(QuestionSpec {
+                    points: Some(1),
+                    category: String::from(category),
+                    ..spec(question, "?")
+                })
+                .unwrap();
+            }
+            let nature = quiz.list_by_category(String::from("Nature"));
+            assert_eq!(nature.len(), 2);
+            assert_eq!(nature[0].0, 0);
+            assert_eq!(nature[1].0, 2);
+            assert_eq!(nature[1].1.question, String::from("What color is grass?"));
+            let math = quiz.list_by_category(String::from("Math"));
+            assert_eq!(math.len(), 1);
+            assert_eq!(math[0].0, 1);
+            assert!(quiz.list_by_category(String::from("nature")).is_empty());
+        }
+
+        /// We test if a valid difficulty is stored and can be retrieved.
+        #[ink::test]
+        fn difficulty_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_full(QuestionSpec {
+                points: Some(1),
+                difficulty: MAX_DIFFICULTY,
+                ..spec(
+                    "What is the airspeed of an unladen swallow?",
+                    "African or European?",
+                )
+            })
+            .unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(quiz.difficulty_of(0), Ok(MAX_DIFFICULTY));
+            assert_eq!(quiz.difficulty_of(1), Ok(MIN_DIFFICULTY));
+            assert_eq!(quiz.difficulty_of(2), Err(Error::QuestionDoesntExist));
+        }
+
+        /// We test if an out-of-range difficulty is rejected.
+        #[ink::test]
+        fn invalid_difficulty_fails() {
+            let mut quiz = Quiz::new();
+            for difficulty in [0, MAX_DIFFICULTY + 1] {
+                assert_eq!(
+                    quiz.add_question_full(QuestionSpec {
+                        points: Some(1),
+                        difficulty,
+                        ..spec("What color is the sky?", "Blue")
+                    }),
+                    Err(Error::InvalidDifficulty)
+                );
+            }
+            assert_eq!(quiz.question_count(), 0);
+        }
+
+        /// We test if the shuffled order is a permutation of all question indices.
+        #[ink::test]
+        fn shuffled_order_is_permutation() {
+            let mut quiz = Quiz::new();
+            let items = (0..10)
+                .map(|i| (format!("Q{}", i), format!("A{}", i)))
+                .collect();
+            quiz.add_questions(items).unwrap();
+            for seed in [0, 1, 42, u64::MAX] {
+                let order = quiz.shuffled_order(seed);
+                assert_eq!(order, quiz.shuffled_order(seed));
+                let mut sorted = order.clone();
+                sorted.sort();
+                assert_eq!(sorted, (0..quiz.question_count()).collect::<Vec<u32>>());
+            }
+            assert!(Quiz::new().shuffled_order(7).is_empty());
+        }
+
+        /// We test if attempts beyond the question's limit are rejected.
+        #[ink::test]
+        fn too_many_attempts_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            quiz.add_question_default(String::from("What color is grass?"), String::from("Green"))
+                .unwrap();
+            assert_eq!(quiz.set_max_attempts(0, 3), Ok(()));
+            assert_eq!(quiz.set_max_attempts(1, 3), Ok(()));
+            for _ in 0..3 {
+                assert_eq!(quiz.answer_and_score(0, String::from("Red")), Ok(false));
+            }
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("Blue")),
+                Err(Error::TooManyAttempts)
+            );
+            assert_eq!(quiz.answer_and_score(1, String::from("Red")), Ok(false));
+            assert_eq!(quiz.answer_and_score(1, String::from("Green")), Ok(true));
+            assert_eq!(
+                quiz.answer_and_score(1, String::from("Green")),
+                Err(Error::AlreadyAnswered)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            // Checking answers isn't counted
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+        }
+
+        /// We test if the deploying account is reported as owner.
+        #[ink::test]
+        fn owner_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let quiz = Quiz::new();
+            assert_eq!(quiz.owner(), accounts.alice);
+        }
+
+        /// We test if scores can be queried for several accounts at once.
+        #[ink::test]
+        fn scores_of_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question(String::from("Q0"), String::from("A0"), Some(3))
+                .unwrap();
+            for player in [accounts.bob, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(player);
+                quiz.answer_and_score(0, String::from("A0")).unwrap();
+            }
+            assert_eq!(
+                quiz.scores_of(vec![accounts.django, accounts.eve, accounts.bob]),
+                vec![(accounts.django, 3), (accounts.eve, 0), (accounts.bob, 3)]
+            );
+        }
+
+        /// We test if resetting scores zeroes every player's score.
+        #[ink::test]
+        fn reset_scores_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            for player in [accounts.bob, accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(player);
+                quiz.answer_and_score(0, String::from("Blue")).unwrap();
+            }
+            assert_eq!(quiz.reset_scores(), Err(Error::InvalidCaller));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.reset_scores(), Ok(()));
+            assert_eq!(quiz.score_of(accounts.bob), 0);
+            assert_eq!(quiz.score_of(accounts.charlie), 0);
+            assert!(quiz.leaderboard(10).is_empty());
+        }
+
+        /// Collects the `(player, new_score)` of every `ScoreUpdated` event.
+        fn score_updates() -> Vec<(AccountId, u32)> {
+            recorded_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::ScoreUpdated(ScoreUpdated { player, new_score }) => {
+                        Some((player, new_score))
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        /// We test if score changes emit `ScoreUpdated` with the new total.
+        #[ink::test]
+        fn score_changes_emit_score_updated() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question(String::from("Q0"), String::from("A0"), Some(2))
+                .unwrap();
+            quiz.add_question(String::from("Q1"), String::from("A1"), Some(3))
+                .unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("Wrong")).unwrap();
+            assert_eq!(score_updates(), vec![(accounts.alice, 2)]);
+            quiz.reset_scores().unwrap();
+            assert_eq!(
+                score_updates(),
+                vec![(accounts.alice, 2), (accounts.alice, 0)]
+            );
+        }
+
+        /// We test if a question matching an existing one, ignoring case, is rejected.
+        #[ink::test]
+        fn duplicate_question_fails() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("WHAT COLOR IS THE SKY?"),
+                    String::from("Blue")
+                ),
+                Err(Error::DuplicateQuestion)
+            );
+            assert_eq!(
+                quiz.add_questions(vec![
+                    (String::from("Q0"), String::from("A0")),
+                    (String::from("q0"), String::from("A0")),
+                ]),
+                Err(Error::DuplicateQuestion)
+            );
+        }
+
+        /// We test if the owner can allow duplicate questions.
+        #[ink::test]
+        fn allow_duplicates_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.set_allow_duplicates(true), Err(Error::InvalidCaller));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.set_allow_duplicates(true), Ok(()));
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    String::from("Blue")
+                ),
+                Ok(())
+            );
+            assert_eq!(quiz.question_count(), 2);
+        }
+
+        /// We test if a question without text is rejected.
+        #[ink::test]
+        fn empty_question_fails() {
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_question_default(String::new(), String::from("Blue")),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(
+                quiz.add_numeric_question(String::new(), 1, 0),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(quiz.question_count(), 0);
+        }
+
+        /// We test if a question without an answer is rejected.
+        #[ink::test]
+        fn empty_answer_fails() {
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_question_default(String::from("What color is the sky?"), String::new()),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(
+                quiz.add_questions(vec![(String::from("Q0"), String::new())]),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(quiz.question_count(), 0);
+        }
+
+        /// We test if whitespace-only fields are rejected, including on edit.
+        #[ink::test]
+        fn whitespace_only_fields_fail() {
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_question_default(String::from("  "), String::from("Blue")),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    String::from("\t\n")
+                ),
+                Err(Error::EmptyField)
+            );
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.edit_question(0, String::from(" "), String::from("Blue"), None),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(
+                quiz.edit_question(
+                    0,
+                    String::from("What color is the sky?"),
+                    String::from(" "),
+                    None
+                ),
+                Err(Error::EmptyField)
+            );
+        }
+
+        /// We test if a question of exactly the maximum length is accepted.
+        #[ink::test]
+        fn max_length_fields_work() {
+            let mut quiz = Quiz::new();
+            let question = "q".repeat(MAX_QUESTION_LEN);
+            let answer = "a".repeat(MAX_ANSWER_LEN);
+            assert_eq!(
+                quiz.add_question_default(question.clone(), answer.clone()),
+                Ok(())
+            );
+            assert_eq!(quiz.check_answer(0, answer.clone()), Ok(true));
+            assert_eq!(quiz.edit_question(0, question, answer, None), Ok(()));
+        }
+
+        /// We test if a question or answer one byte over the maximum is rejected.
+        #[ink::test]
+        fn too_long_fields_fail() {
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_question_default("q".repeat(MAX_QUESTION_LEN + 1), String::from("Blue")),
+                Err(Error::TooLong)
+            );
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    "a".repeat(MAX_ANSWER_LEN + 1)
+                ),
+                Err(Error::TooLong)
+            );
+            // Length is measured in bytes, so multi-byte characters count for more
+            assert_eq!(
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    "é".repeat(MAX_ANSWER_LEN / 2 + 1)
+                ),
+                Err(Error::TooLong)
+            );
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.edit_question(
+                    0,
+                    "q".repeat(MAX_QUESTION_LEN + 1),
+                    String::from("Blue"),
+                    None
+                ),
+                Err(Error::TooLong)
+            );
+        }
+
+        /// We test if a player's answered status is reported.
+        #[ink::test]
+        fn has_answered_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.start_session().unwrap();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert!(!quiz.has_answered(accounts.alice, 0));
+            quiz.answer_and_score(0, String::from("Blue")).unwrap();
+            assert!(quiz.has_answered(accounts.alice, 0));
+            assert!(!quiz.has_answered(accounts.bob, 0));
+        }
+
+        /// We test if several answers can be checked at once.
+        #[ink::test]
+        fn check_answers_works() {
+            let quiz = quiz_with_three_questions();
+            assert_eq!(
+                quiz.check_answers(vec![
+                    (0, String::from("A0")),
+                    (1, String::from("A2")),
+                    (3, String::from("A3")),
+                    (2, String::from("A2")),
+                ]),
+                vec![
+                    Ok(true),
+                    Ok(false),
+                    Err(Error::QuestionDoesntExist),
+                    Ok(true)
+                ]
+            );
+        }
+
+        /// We test if the exposed answer hash matches the salted answer and question id.
+        #[ink::test]
+        fn answer_hash_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("What color is the sky?"), String::from("Blue"))
+                .unwrap();
+            assert_eq!(
+                quiz.answer_hash(0),
+                Ok(Quiz::hash::<Blake2x256, _>((
+                    0u32,
+                    quiz.salt,
+                    String::from("Blue")
+                )))
+            );
+            assert_eq!(quiz.answer_hash(1), Err(Error::QuestionDoesntExist));
+        }
+
+        /// We test if adding and removing educators emits events.
+        #[ink::test]
+        fn educator_changes_emit_events() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_educator(accounts.bob).unwrap();
+            quiz.remove_educator(accounts.bob).unwrap();
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            let Event::EducatorAdded(EducatorAdded { educator }) = &events[0] else {
+                panic!("expected EducatorAdded");
+            };
+            assert_eq!(*educator, accounts.bob);
+            let Event::EducatorRemoved(EducatorRemoved { educator }) = &events[1] else {
+                panic!("expected EducatorRemoved");
+            };
+            assert_eq!(*educator, accounts.bob);
+        }
+
+        /// We test if an educator can give up their role.
+        #[ink::test]
+        fn educator_can_renounce() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_educator(accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.renounce_educator(), Ok(()));
+            assert_eq!(quiz.power_level_of(accounts.bob), None);
+            assert_eq!(quiz.renounce_educator(), Err(Error::NotRegistered));
+        }
+
+        /// We test if the owner can't give up their educator role.
+        #[ink::test]
+        fn owner_cannot_renounce() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.renounce_educator(), Err(Error::InvalidCaller));
+            assert_eq!(
+                quiz.power_level_of(accounts.alice),
+                Some(PowerLevel::Educator)
+            );
+        }
+
+        /// We test if the total of all questions' points is reported.
+        #[ink::test]
+        fn total_points_works() {
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.total_points(), 0);
+            for (i, points) in [3, 5, 2].into_iter().enumerate() {
+                quiz.add_question(format!("Q{}", i), format!("A{}", i), Some(points))
+                    .unwrap();
+            }
+            assert_eq!(quiz.total_points(), 10);
+            quiz.add_question(String::from("Q3"), String::from("A3"), Some(u32::MAX))
+                .unwrap();
+            assert_eq!(quiz.total_points(), u32::MAX);
+        }
+
+        /// We test if scored answers are only accepted during a session.
+        #[ink::test]
+        fn scoring_requires_active_session() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::NoActiveSession)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.start_session(), Err(Error::NotRegistered));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.start_session(), Ok(()));
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            assert_eq!(quiz.end_session(), Ok(()));
+            assert_eq!(
+                quiz.answer_and_score(1, String::from("A1")),
+                Err(Error::NoActiveSession)
+            );
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+        }
+
+        /// We test if `answer_current` targets whichever question is active.
+        #[ink::test]
+        fn answer_current_follows_pointer() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(
+                quiz.answer_current(String::from("A0")),
+                Err(Error::NoActiveSession)
+            );
+            assert_eq!(
+                quiz.set_current_question(3),
+                Err(Error::QuestionDoesntExist)
+            );
+            assert_eq!(quiz.set_current_question(0), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.set_current_question(1), Err(Error::NotRegistered));
+            assert_eq!(quiz.answer_current(String::from("A0")), Ok(true));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            quiz.set_current_question(2).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(quiz.answer_current(String::from("A0")), Ok(false));
+            assert!(quiz.has_answered(accounts.charlie, 2));
+            assert!(!quiz.has_answered(accounts.charlie, 0));
+        }
+
+        /// We test if faster answers to the current question score more.
+        #[ink::test]
+        fn faster_answers_score_more() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question(String::from("Q0"), String::from("A0"), Some(10))
+                .unwrap();
+            quiz.start_session().unwrap();
+            quiz.set_current_question(0).unwrap();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_current(String::from("A0")).unwrap();
+            for _ in 0..5 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            quiz.answer_current(String::from("A0")).unwrap();
+            for _ in 0..20 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_current(String::from("A0")).unwrap();
+            assert_eq!(quiz.score_of(accounts.bob), 9);
+            assert_eq!(quiz.score_of(accounts.charlie), 4);
+            assert_eq!(quiz.score_of(accounts.django), MIN_TIMED_POINTS);
+        }
+
+        /// We test if the streak grows on consecutive correct answers and
+        /// multiplies the points awarded.
+        #[ink::test]
+        fn streak_grows_on_correct_answers() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.streak_of(accounts.alice), 0);
+            for i in 0..3 {
+                quiz.answer_and_score(i, format!("A{}", i)).unwrap();
+                assert_eq!(quiz.streak_of(accounts.alice), i + 1);
+            }
+            assert_eq!(quiz.score_of(accounts.alice), 1 + 2 + 3);
+        }
+
+        /// We test if a wrong answer resets the streak.
+        #[ink::test]
+        fn streak_resets_after_miss() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("Wrong")).unwrap();
+            assert_eq!(quiz.streak_of(accounts.alice), 0);
+            quiz.answer_and_score(2, String::from("A2")).unwrap();
+            assert_eq!(quiz.streak_of(accounts.alice), 1);
+            assert_eq!(quiz.score_of(accounts.alice), 2);
+        }
+
+        /// We test if only the owner can choose the reward token.
+        #[ink::test]
+        fn only_owner_sets_reward_token() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.set_reward_token(Some(accounts.eve)),
+                Err(Error::InvalidCaller)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.set_reward_token(Some(accounts.eve)), Ok(()));
+            assert_eq!(quiz.set_reward_token(None), Ok(()));
+        }
+
+        /// We test if a fetched question shows its text but not its answer.
+        #[ink::test]
+        fn get_hides_answer() {
+            let quiz = quiz_with_three_questions();
+            assert_eq!(
+                quiz.get(1),
+                Ok(QuestionView {
+                    question: String::from("Q1"),
+                    points: 1,
+                    options: Vec::new(),
+                    category: String::new(),
+                })
+            );
+            assert!(!format!("{:?}", quiz.get(1).unwrap()).contains("answer"));
+        }
+
+        /// We test if the owner can withdraw from the pool after the session.
+        #[ink::test]
+        fn withdraw_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            assert_eq!(quiz.withdraw(40), Err(Error::SessionActive));
+            quiz.end_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.withdraw(40), Err(Error::InvalidCaller));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.withdraw(40), Ok(()));
+            assert_eq!(balance_of(accounts.frank), 60);
+            assert_eq!(balance_of(accounts.alice), 940);
+        }
+
+        /// We test if withdrawing more than the pool holds is rejected.
+        #[ink::test]
+        fn over_withdrawal_fails() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            quiz.end_session().unwrap();
+            assert_eq!(quiz.withdraw(101), Err(Error::InsufficientBalance));
+            assert_eq!(balance_of(accounts.frank), 100);
+        }
+
+        /// We test if the title given at construction is stored.
+        #[ink::test]
+        fn new_with_title_works() {
+            let quiz = Quiz::new_with_title(String::from("Capitals"));
+            assert_eq!(quiz.title(), String::from("Capitals"));
+            assert_eq!(Quiz::new().title(), String::new());
+        }
+
+        /// We test if only the owner can rename the quiz.
+        #[ink::test]
+        fn set_title_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new_with_title(String::from("Capitals"));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.set_title(String::from("Rivers")),
+                Err(Error::InvalidCaller)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.set_title(String::from("Rivers")), Ok(()));
+            assert_eq!(quiz.title(), String::from("Rivers"));
+        }
+
+        /// We test if a disabled question rejects scored answers but can
+        /// still be fetched.
+        #[ink::test]
+        fn disabled_question_rejects_answers() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.set_question_enabled(1, false),
+                Err(Error::NotRegistered)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(quiz.set_question_enabled(1, false), Ok(()));
+            assert_eq!(
+                quiz.answer_and_score(1, String::from("A1")),
+                Err(Error::QuestionDisabled)
+            );
+            assert_eq!(quiz.get(1).unwrap().question, String::from("Q1"));
+            assert_eq!(quiz.score_of(accounts.alice), 0);
+        }
+
+        /// We test if re-enabling a question accepts answers again.
+        #[ink::test]
+        fn reenabled_question_accepts_answers() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.set_question_enabled(1, false).unwrap();
+            quiz.set_question_enabled(1, true).unwrap();
+            assert_eq!(quiz.answer_and_score(1, String::from("A1")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+        }
+
+        /// We test if every answer of a multi-answer question is accepted.
+        #[ink::test]
+        fn multi_answer_question_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_multi(
+                String::from("Which country has 50 states?"),
+                vec![
+                    String::from("USA"),
+                    String::from("United States"),
+                    String::from("America"),
+                ],
+            )
+            .unwrap();
+            for answer in ["USA", "United States", "America"] {
+                assert_eq!(quiz.check_answer(0, String::from(answer)), Ok(true));
+            }
+            assert_eq!(quiz.check_answer(0, String::from("Canada")), Ok(false));
+        }
+
+        /// We test if a multi-answer question needs at least one answer.
+        #[ink::test]
+        fn multi_answer_question_needs_answers() {
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_question_multi(String::from("Q"), Vec::new()),
+                Err(Error::EmptyField)
+            );
+        }
+
+        /// We test if completion counts answered questions out of all of them.
+        #[ink::test]
+        fn completion_of_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.completion_of(accounts.alice), 0);
+            for i in 0..4 {
+                quiz.add_question_default(format!("Q{}", i), format!("A{}", i))
+                    .unwrap();
+            }
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.answer_and_score(2, String::from("wrong")), Ok(false));
+            assert_eq!(quiz.completion_of(accounts.alice), 25);
+            assert_eq!(quiz.completion_of(accounts.bob), 0);
+        }
+
+        /// We test if starting and ending a session emit events with the
+        /// current block.
+        #[ink::test]
+        fn session_changes_emit_events() {
+            let mut quiz = Quiz::new();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            quiz.start_session().unwrap();
+            for _ in 0..3 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            quiz.end_session().unwrap();
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            let Event::SessionStarted(SessionStarted { start_block }) = &events[0] else {
+                panic!("expected SessionStarted");
+            };
+            assert_eq!(*start_block, 1);
+            let Event::SessionEnded(SessionEnded { end_block }) = &events[1] else {
+                panic!("expected SessionEnded");
+            };
+            assert_eq!(*end_block, 4);
+        }
+
+        /// We test if changing the default points only affects questions added afterwards.
+        #[ink::test]
+        fn default_points_apply_to_new_questions() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question(String::from("Q0"), String::from("A0"), None)
+                .unwrap();
+            quiz.set_default_points(5).unwrap();
+            quiz.add_question(String::from("Q1"), String::from("A1"), None)
+                .unwrap();
+            quiz.add_question_default(String::from("Q2"), String::from("A2"))
+                .unwrap();
+            quiz.add_question(String::from("Q3"), String::from("A3"), Some(2))
+                .unwrap();
+            assert_eq!(quiz.get(0).unwrap().points, 1);
+            assert_eq!(quiz.get(1).unwrap().points, 5);
+            assert_eq!(quiz.get(2).unwrap().points, 5);
+            assert_eq!(quiz.get(3).unwrap().points, 2);
+
+            quiz.add_questions(vec![(String::from("Q4"), String::from("A4"))])
+                .unwrap();
+            quiz.add_multiple_choice(
+                String::from("Q5"),
+                vec![String::from("A5"), String::from("B5")],
+                0,
+            )
+            .unwrap();
+            quiz.add_question_multi(String::from("Q6"), vec![String::from("A6")])
+                .unwrap();
+            quiz.add_numeric_question(String::from("Q7"), 7, 0).unwrap();
+            for index in 4..8 {
+                assert_eq!(quiz.get(index).unwrap().points, 5);
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.set_default_points(7), Err(Error::InvalidCaller));
+        }
+
+        /// We test if the largest possible index is rejected rather than wrapping.
+        #[ink::test]
+        fn max_index_doesnt_exist() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            assert_eq!(quiz.get(u32::MAX), Err(Error::QuestionDoesntExist));
+            assert_eq!(
+                quiz.check_answer(u32::MAX, String::from("A0")),
+                Err(Error::QuestionDoesntExist)
+            );
+        }
+
+        /// We test if clearing the questions resets the quiz for new ones.
+        #[ink::test]
+        fn clear_questions_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            quiz.add_question_default(String::from("Q1"), String::from("A1"))
+                .unwrap();
+            quiz.start_session().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.clear_questions().unwrap();
+            assert_eq!(quiz.question_count(), 0);
+            assert_eq!(quiz.get(0), Err(Error::QuestionDoesntExist));
+            assert_eq!(quiz.correct_count_of(0), 0);
+
+            quiz.add_question_default(String::from("Q0"), String::from("New"))
+                .unwrap();
+            assert_eq!(quiz.question_count(), 1);
+            assert_eq!(quiz.check_answer(0, String::from("New")), Ok(true));
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert!(!quiz.has_answered(accounts.alice, 0));
+            assert_eq!(quiz.answer_and_score(0, String::from("New")), Ok(true));
+        }
+
+        /// We test clearing the questions also forgets attempts by accounts that
+        /// never registered or scored.
+        #[ink::test]
+        fn clear_questions_forgets_unregistered_players() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.answer_and_score(0, String::from("Wrong")), Ok(false));
+            assert!(quiz.has_answered(accounts.bob, 0));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            quiz.clear_questions().unwrap();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            assert!(!quiz.has_answered(accounts.bob, 0));
+        }
+
+        /// We test if hints are returned only for questions that have one.
+        #[ink::test]
+        fn hint_of_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_full(QuestionSpec {
+                hint: Some(String::from("Look up")),
+                ..spec("What color is the sky?", "Blue")
+            })
+            .unwrap();
+            quiz.add_question_default(String::from("Q1"), String::from("A1"))
+                .unwrap();
+            assert_eq!(quiz.hint_of(0), Ok(Some(String::from("Look up"))));
+            assert_eq!(quiz.hint_of(1), Ok(None));
+            assert_eq!(quiz.hint_of(2), Err(Error::QuestionDoesntExist));
+
+            quiz.edit_question(
+                1,
+                String::from("Q1"),
+                String::from("A1"),
+                Some(String::from("A")),
+            )
+            .unwrap();
+            assert_eq!(quiz.hint_of(1), Ok(Some(String::from("A"))));
+        }
+
+        /// We test if only the first correct answerer is recorded as first solver.
+        #[ink::test]
+        fn first_solver_is_kept() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.first_solver_of(0), None);
+            for player in [accounts.bob, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(player);
+                assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            }
+            assert_eq!(quiz.first_solver_of(0), Some(accounts.bob));
+        }
+
+        /// We test if answers stored and checked with each hash algorithm match.
+        #[ink::test]
+        fn every_hash_algo_checks_answers() {
+            let mut hashes = Vec::new();
+            for hash_algo in [
+                HashAlgo::Blake2x256,
+                HashAlgo::Keccak256,
+                HashAlgo::Sha2x256,
+            ] {
+                let mut quiz = Quiz::new_with_hash_algo(hash_algo);
+                quiz.add_question_default(
+                    String::from("What color is the sky?"),
+                    String::from("Blue"),
+                )
+                .unwrap();
+                assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+                assert_eq!(quiz.check_answer(0, String::from("Red")), Ok(false));
+                hashes.push(quiz.answer_hash(0).unwrap());
+            }
+            assert_ne!(hashes[0], hashes[1]);
+            assert_ne!(hashes[1], hashes[2]);
+            assert_ne!(hashes[0], hashes[2]);
+        }
+
+        /// We test if an educator can answer for a student, but only once.
+        #[ink::test]
+        fn answer_for_credits_player() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question(String::from("Q0"), String::from("A0"), Some(3))
+                .unwrap();
+            quiz.start_session().unwrap();
+            assert_eq!(
+                quiz.answer_for(accounts.bob, 0, String::from("A0")),
+                Ok(true)
+            );
+            assert_eq!(quiz.score_of(accounts.bob), 3);
+            assert_eq!(quiz.score_of(accounts.alice), 0);
+            assert!(quiz.has_answered(accounts.bob, 0));
+            assert_eq!(
+                quiz.answer_for(accounts.bob, 0, String::from("A0")),
+                Err(Error::AlreadyAnswered)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                quiz.answer_for(accounts.django, 0, String::from("A0")),
+                Err(Error::NotRegistered)
+            );
+        }
+
+        /// We test if a player's profile gathers their score, streak and answers.
+        #[ink::test]
+        fn profile_of_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            for i in 0..3 {
+                quiz.add_question_default(format!("Q{}", i), format!("A{}", i))
+                    .unwrap();
+            }
+            quiz.start_session().unwrap();
+            assert_eq!(
+                quiz.profile_of(accounts.bob),
+                PlayerProfile {
+                    score: 0,
+                    streak: 0,
+                    answered_count: 0,
+                }
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            // The second correct answer in a row is worth double
+            assert_eq!(
+                quiz.profile_of(accounts.bob),
+                PlayerProfile {
+                    score: 3,
+                    streak: 2,
+                    answered_count: 2,
+                }
+            );
+        }
+
+        /// We test if the number of educators is capped, and removing one frees a slot.
+        #[ink::test]
+        fn educator_limit_works() {
+            let mut quiz = Quiz::new();
+            // The owner already takes one slot
+            for i in 1..MAX_EDUCATORS {
+                quiz.add_educator(AccountId::from([0x10 + i as u8; 32]))
+                    .unwrap();
+            }
+            let extra = AccountId::from([0xff; 32]);
+            assert_eq!(quiz.add_educator(extra), Err(Error::EducatorLimitReached));
+            // Re-adding an existing educator doesn't take another slot
+            assert_eq!(quiz.add_educator(AccountId::from([0x11; 32])), Ok(()));
+
+            quiz.remove_educator(AccountId::from([0x11; 32])).unwrap();
+            assert_eq!(quiz.add_educator(extra), Ok(()));
+            assert_eq!(
+                quiz.add_educator(AccountId::from([0x11; 32])),
+                Err(Error::EducatorLimitReached)
+            );
+        }
+
+        /// We test if educators are added in a batch, or not at all past the cap.
+        #[ink::test]
+        fn add_educators_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            assert_eq!(
+                quiz.add_educators(vec![accounts.bob, accounts.charlie, accounts.bob]),
+                Ok(())
+            );
+            assert_eq!(
+                quiz.power_level_of(accounts.bob),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(
+                quiz.power_level_of(accounts.charlie),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(quiz.educator_count, 3);
+
+            let batch: Vec<AccountId> = (0..MAX_EDUCATORS - 2)
+                .map(|i| AccountId::from([0x20 + i as u8; 32]))
+                .collect();
+            assert_eq!(quiz.add_educators(batch), Err(Error::EducatorLimitReached));
+            assert_eq!(quiz.educator_count, 3);
+            assert_eq!(quiz.power_level_of(AccountId::from([0x20; 32])), None);
+        }
+
+        /// We test if the points returned for an answer include the streak multiplier.
+        #[ink::test]
+        fn answer_and_score_points_works() {
+            let mut quiz = Quiz::new();
+            for (i, points) in [2, 5, 3].into_iter().enumerate() {
+                quiz.add_question(format!("Q{}", i), format!("A{}", i), Some(points))
+                    .unwrap();
+            }
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.answer_and_score_points(0, String::from("A0")), Ok(2));
+            assert_eq!(quiz.answer_and_score_points(1, String::from("A1")), Ok(10));
+            assert_eq!(
+                quiz.answer_and_score_points(2, String::from("Wrong")),
+                Ok(0)
+            );
+            assert_eq!(
+                quiz.answer_and_score_points(2, String::from("A2")),
+                Err(Error::TooManyAttempts)
+            );
+        }
+
+        /// We test if each session keeps its own scoreboard.
+        #[ink::test]
+        fn leaderboard_for_keeps_past_sessions() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.session_id(), 1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            quiz.end_session().unwrap();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.session_id(), 2);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+
+            assert_eq!(quiz.leaderboard_for(1, 10), vec![(accounts.bob, 1)]);
+            assert_eq!(quiz.leaderboard_for(2, 10), vec![(accounts.django, 1)]);
+            assert_eq!(quiz.leaderboard(10), vec![(accounts.django, 1)]);
+            assert_eq!(quiz.score_of(accounts.bob), 0);
+            assert!(quiz.leaderboard_for(3, 10).is_empty());
+        }
+
+        /// We test a question answered in one session can be scored again, with a
+        /// fresh streak, in the next.
+        #[ink::test]
+        fn sessions_keep_separate_attempts() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            assert_eq!(quiz.streak_of(accounts.alice), 2);
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::AlreadyAnswered)
+            );
+
+            quiz.end_session().unwrap();
+            quiz.start_session().unwrap();
+            assert!(!quiz.has_answered(accounts.alice, 0));
+            assert_eq!(quiz.streak_of(accounts.alice), 0);
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            assert_eq!(quiz.score_of(accounts.alice), 1);
+            assert_eq!(quiz.leaderboard_for(1, 10), vec![(accounts.alice, 3)]);
+        }
+
+        /// We test a player solving a question in two sessions counts as one
+        /// correct answer, while both attempts are counted.
+        #[ink::test]
+        fn correct_count_spans_sessions() {
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.end_session().unwrap();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            assert_eq!(quiz.correct_count_of(0), 1);
+            assert_eq!(quiz.question_stats(0), Ok((2, 1)));
+        }
+
+        /// We test if a question's stats count every attempt and the correct ones.
+        #[ink::test]
+        fn question_stats_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.set_max_attempts(0, 2).unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("Wrong")).unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(0, String::from("Wrong")).unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            assert_eq!(quiz.question_stats(0), Ok((3, 1)));
+            assert_eq!(quiz.question_stats(1), Ok((1, 1)));
+            assert_eq!(quiz.question_stats(2), Ok((0, 0)));
+            assert_eq!(quiz.question_stats(3), Err(Error::QuestionDoesntExist));
+        }
+
+        /// We test if answers must come with at least the answer fee.
+        #[ink::test]
+        fn answer_fee_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.set_answer_fee(10).unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(9);
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::FeeTooLow)
+            );
+            assert!(!quiz.has_answered(accounts.bob, 0));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(25);
+            assert_eq!(quiz.answer_and_score(1, String::from("A1")), Ok(true));
+        }
+
+        /// We test if the owner can settle a question by hand, but only once.
+        #[ink::test]
+        fn force_set_winner_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = funded_quiz();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.bob, 0);
+            assert_eq!(quiz.force_set_winner(0, accounts.bob), Ok(()));
+            assert_eq!(quiz.first_solver_of(0), Some(accounts.bob));
+            assert_eq!(balance_of(accounts.bob), 10);
+            assert_eq!(balance_of(accounts.frank), 90);
+            assert_eq!(
+                quiz.force_set_winner(0, accounts.django),
+                Err(Error::AlreadyClaimed)
+            );
+            assert_eq!(quiz.first_solver_of(0), Some(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.force_set_winner(0, accounts.bob),
+                Err(Error::InvalidCaller)
+            );
+        }
+
+        /// We test if every field of a question spec round-trips through the getters.
+        #[ink::test]
+        fn add_question_full_works() {
+            let mut quiz = Quiz::new();
+            let spec = QuestionSpec {
+                question: String::from("What color is the sky?"),
+                answer: String::from("Blue"),
+                points: Some(4),
+                category: String::from("Nature"),
+                difficulty: 3,
+                hint: Some(String::from("Look up")),
+                image_uri: Some(String::from("ipfs://sky")),
+                deadline: Some(10),
+            };
+            assert_eq!(quiz.add_question_full(spec), Ok(()));
+            assert_eq!(
+                quiz.get(0),
+                Ok(QuestionView {
+                    question: String::from("What color is the sky?"),
+                    points: 4,
+                    options: Vec::new(),
+                    category: String::from("Nature"),
+                })
+            );
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.difficulty_of(0), Ok(3));
+            assert_eq!(quiz.hint_of(0), Ok(Some(String::from("Look up"))));
+            assert_eq!(quiz.image_of(0), Ok(Some(String::from("ipfs://sky"))));
+            assert_eq!(quiz.blocks_remaining(0), Some(10));
+        }
+
+        /// We test if players who both registered and scored are counted once.
+        #[ink::test]
+        fn player_count_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.player_count(), 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.register().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            assert_eq!(quiz.player_count(), 2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            quiz.register().unwrap();
+            assert_eq!(quiz.player_count(), 3);
+            assert_eq!(quiz.stats().player_count, 3);
+        }
+
+        /// We test if scored answers are only accepted within the quiz window.
+        #[ink::test]
+        fn window_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.set_window(2, 3).unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::WindowClosed)
+            );
+            assert_eq!(quiz.check_answer(0, String::from("A0")), Ok(true));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(quiz.answer_and_score(1, String::from("A1")), Ok(true));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                quiz.answer_and_score(2, String::from("A2")),
+                Err(Error::WindowClosed)
+            );
+        }
+
+        /// We test if an inverted window is rejected and a window can be cleared.
+        #[ink::test]
+        fn set_and_clear_window() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            assert_eq!(quiz.set_window(3, 2), Err(Error::InvalidWindow));
+            assert_eq!(quiz.set_window(5, 5), Ok(()));
+            quiz.start_session().unwrap();
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::WindowClosed)
+            );
+            assert_eq!(quiz.clear_window(), Ok(()));
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.clear_window(), Err(Error::InvalidCaller));
+        }
+
+        /// We test the role predicates for the owner, an educator, a user and a stranger.
+        #[ink::test]
+        fn role_predicates_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_educator(accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            quiz.register().unwrap();
+            for (who, owner, educator) in [
+                (accounts.alice, true, true),
+                (accounts.bob, false, true),
+                (accounts.charlie, false, false),
+                (accounts.django, false, false),
+            ] {
+                assert_eq!(quiz.is_owner(who), owner);
+                assert_eq!(quiz.is_educator(who), educator);
+            }
+        }
+
+        /// We test if registering emits an event once, and not when repeated.
+        #[ink::test]
+        fn register_emits_event() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.register().unwrap();
+            assert_eq!(quiz.register(), Err(Error::InvalidPowerLevel));
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            let Event::PlayerRegistered(PlayerRegistered { player }) = &events[0] else {
+                panic!("expected PlayerRegistered");
+            };
+            assert_eq!(*player, accounts.bob);
+        }
+
+        /// We test if the exposed hashing matches what the contract stores.
+        #[ink::test]
+        fn compute_answer_hash_works() {
+            let mut quiz = Quiz::new_with_hash_algo(HashAlgo::Keccak256);
+            quiz.add_question_default(String::from("Q0"), String::from("Blue"))
+                .unwrap();
+            quiz.add_case_insensitive_question(String::from("Q1"), String::from("Blue"), 1)
+                .unwrap();
+            assert_eq!(
+                quiz.compute_answer_hash(0, String::from("Blue")),
+                quiz.answer_hash(0)
+            );
+            assert_eq!(
+                quiz.compute_answer_hash(1, String::from(" BLUE ")),
+                quiz.answer_hash(1)
+            );
+            assert_ne!(
+                quiz.compute_answer_hash(0, String::from("Red")),
+                quiz.answer_hash(0)
+            );
+            assert_eq!(
+                quiz.compute_answer_hash(2, String::from("Blue")),
+                Err(Error::QuestionDoesntExist)
+            );
+        }
+
+        /// We test if the version message reports the contract version.
+        #[ink::test]
+        fn version_works() {
+            let quiz = Quiz::new();
+            assert_eq!(quiz.version(), VERSION);
+        }
+
+        /// We test if attempts within the cooldown are rejected, and allowed after it.
+        #[ink::test]
+        fn cooldown_works() {
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            quiz.set_max_attempts(0, 3).unwrap();
+            quiz.set_cooldown_blocks(2).unwrap();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.answer_and_score(0, String::from("Wrong")), Ok(false));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                quiz.answer_and_score(0, String::from("A0")),
+                Err(Error::CooldownActive)
+            );
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(quiz.answer_and_score(0, String::from("A0")), Ok(true));
+        }
+
+        /// We test if exporting returns every question in order.
+        #[ink::test]
+        fn export_questions_works() {
+            let mut quiz = Quiz::new();
+            assert!(quiz.export_questions().is_empty());
+            for i in 0..4 {
+                quiz.add_question_default(format!("Q{}", i), format!("A{}", i))
+                    .unwrap();
+            }
+            let export = quiz.export_questions();
+            assert_eq!(export.len() as u32, quiz.question_count());
+            assert_eq!(export[0].question, String::from("Q0"));
+            assert_eq!(export[3].question, String::from("Q3"));
+        }
+
+        /// We test if changing a question's points leaves earned scores alone.
+        #[ink::test]
+        fn set_points_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_question_default(String::from("Q0"), String::from("A0"))
+                .unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            assert_eq!(quiz.set_points(0, 5), Err(Error::NotRegistered));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            quiz.set_points(0, 5).unwrap();
+            assert_eq!(quiz.get(0).unwrap().points, 5);
+            assert_eq!(quiz.score_of(accounts.bob), 1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            assert_eq!(quiz.score_of(accounts.django), 5);
+        }
+
+        /// We test if unregistered, wrong-role and educator callers get distinct outcomes.
+        #[ink::test]
+        fn add_question_checks_registration() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                quiz.add_question_default(String::from("Q0"), String::from("A0")),
+                Err(Error::NotRegistered)
+            );
+            quiz.register().unwrap();
+            assert_eq!(
+                quiz.add_question_default(String::from("Q0"), String::from("A0")),
+                Err(Error::InvalidPowerLevel)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                quiz.add_question_default(String::from("Q0"), String::from("A0")),
+                Ok(())
+            );
+        }
+
+        /// We test if image URIs are stored, and rejected when blank or too long.
+        #[ink::test]
+        fn image_of_works() {
+            let mut quiz = Quiz::new();
+            let add = |quiz: &mut Quiz, question: &str, image_uri: Option<String>| {
+                quiz.add_question_full(QuestionSpec {
+                    image_uri,
+                    ..spec(question, "A")
+                })
+            };
+            let cid =
+                String::from("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+            assert_eq!(add(&mut quiz, "Q0", Some(cid.clone())), Ok(()));
+            assert_eq!(add(&mut quiz, "Q1", None), Ok(()));
+            assert_eq!(quiz.image_of(0), Ok(Some(cid)));
+            assert_eq!(quiz.image_of(1), Ok(None));
+            assert_eq!(quiz.image_of(2), Err(Error::QuestionDoesntExist));
+            assert_eq!(
+                add(&mut quiz, "Q2", Some(String::from(" "))),
+                Err(Error::EmptyField)
+            );
+            assert_eq!(
+                add(&mut quiz, "Q2", Some("u".repeat(MAX_IMAGE_URI_LEN + 1))),
+                Err(Error::TooLong)
+            );
+        }
+
+        /// We test if removing and clearing questions emit QuestionRemoved events.
+        #[ink::test]
+        fn question_removed_emits_events() {
+            let mut quiz = quiz_with_three_questions();
+            let before = recorded_events().len();
+            quiz.remove_question(1).unwrap();
+            quiz.clear_questions().unwrap();
+            let removed: Vec<u32> = recorded_events()
+                .into_iter()
+                .skip(before)
+                .filter_map(|event| match event {
+                    Event::QuestionRemoved(QuestionRemoved { index }) => Some(index),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(removed, vec![1, 0, 1]);
+        }
+
+        /// We test the blocks left before a deadline, once passed and when unset.
+        #[ink::test]
+        fn blocks_remaining_works() {
+            let mut quiz = quiz_with_three_questions();
+            quiz.set_deadline(0, 5).unwrap();
+            quiz.set_deadline(1, 1).unwrap();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(quiz.blocks_remaining(0), Some(3));
+            assert_eq!(quiz.blocks_remaining(1), None);
+            assert_eq!(quiz.blocks_remaining(2), None);
+        }
+
+        /// We test if the paused getter follows pause and unpause.
+        #[ink::test]
+        fn is_paused_works() {
+            let mut quiz = Quiz::new();
+            assert!(!quiz.is_paused());
+            quiz.pause().unwrap();
+            assert!(quiz.is_paused());
+            quiz.unpause().unwrap();
+            assert!(!quiz.is_paused());
+        }
+
+        /// We test if educator rights move in one call, and only from an educator.
+        #[ink::test]
+        fn transfer_educator_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new();
+            quiz.add_educator(accounts.bob).unwrap();
+            assert_eq!(
+                quiz.transfer_educator(accounts.bob, accounts.charlie),
+                Ok(())
+            );
+            assert_eq!(quiz.power_level_of(accounts.bob), None);
+            assert_eq!(
+                quiz.power_level_of(accounts.charlie),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(quiz.educator_count, 2);
+            assert_eq!(
+                quiz.transfer_educator(accounts.django, accounts.eve),
+                Err(Error::InvalidPowerLevel)
+            );
+            assert_eq!(
+                quiz.transfer_educator(accounts.alice, accounts.eve),
+                Err(Error::InvalidCaller)
+            );
+            assert_eq!(quiz.power_level_of(accounts.eve), None);
+        }
+
+        /// We test how answers are normalized under each combination of options.
+        #[ink::test]
+        fn normalize_answer_works() {
+            let exact = NormalizeOpts {
+                case_sensitive: true,
+                trim: false,
+            };
+            let folded = NormalizeOpts {
+                case_sensitive: false,
+                trim: true,
+            };
+            assert_eq!(normalize_answer(" Blue ", NormalizeOpts::default()), "Blue");
+            assert_eq!(normalize_answer(" Blue ", exact), " Blue ");
+            assert_eq!(normalize_answer(" BLUE\n", folded), "blue");
+            assert_eq!(normalize_answer("Ärger", folded), "ärger");
+            assert_eq!(normalize_answer("", folded), "");
+        }
+
+        /// We test if stored answers and attempts are normalized the same way.
+        #[ink::test]
+        fn normalization_is_symmetric() {
+            let mut quiz = Quiz::new();
+            quiz.add_case_insensitive_question(String::from("Q0"), String::from(" Blue "), 1)
+                .unwrap();
+            quiz.add_question_default(String::from("Q1"), String::from("Blue"))
+                .unwrap();
+            for attempt in ["Blue", "blue", "  BLUE "] {
+                assert_eq!(quiz.check_answer(0, String::from(attempt)), Ok(true));
+            }
+            assert_eq!(quiz.check_answer(1, String::from(" Blue\t")), Ok(true));
+            assert_eq!(quiz.check_answer(1, String::from("blue")), Ok(false));
+        }
+
+        /// We test if fetching several questions keeps their positions.
+        #[ink::test]
+        fn get_many_works() {
+            let quiz = quiz_with_three_questions();
+            let questions = quiz.get_many(vec![2, 7, 0, u32::MAX]);
+            assert_eq!(questions.len(), 4);
+            assert_eq!(questions[0].as_ref().unwrap().question, String::from("Q2"));
+            assert_eq!(questions[1], Err(Error::QuestionDoesntExist));
+            assert_eq!(questions[2].as_ref().unwrap().question, String::from("Q0"));
+            assert_eq!(questions[3], Err(Error::QuestionDoesntExist));
+        }
+
+        /// We test if only answers matching their hash are revealed, after the session.
+        #[ink::test]
+        fn reveal_answers_works() {
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(
+                quiz.reveal_answers(vec![(0, String::from("A0"))]),
+                Err(Error::SessionActive)
+            );
+            quiz.end_session().unwrap();
+            assert_eq!(
+                quiz.reveal_answers(vec![(0, String::from("A0")), (1, String::from("Wrong"))]),
+                Err(Error::WrongAnswer)
+            );
+            assert_eq!(quiz.revealed_answer(0), None);
+            assert_eq!(
+                quiz.reveal_answers(vec![(0, String::from("A0")), (1, String::from("A1"))]),
+                Ok(())
+            );
+            assert_eq!(quiz.revealed_answer(0), Some(String::from("A0")));
+            assert_eq!(quiz.revealed_answer(1), Some(String::from("A1")));
+            assert_eq!(quiz.revealed_answer(2), None);
+        }
+
+        /// We test if the quiz stats follow the answers given.
+        #[ink::test]
+        fn stats_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("Wrong")).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            // Django's second correct answer in a row is worth double
+            assert_eq!(
+                quiz.stats(),
+                QuizStats {
+                    question_count: 3,
+                    player_count: 2,
+                    total_answers: 4,
+                    total_points_awarded: 4,
+                }
+            );
+        }
+
+        /// We test if weighted picks are deterministic and skip pointless questions.
+        #[ink::test]
+        fn pick_weighted_works() {
+            let mut quiz = Quiz::new();
+            assert_eq!(quiz.pick_weighted(0), None);
+            for (i, points) in [2, 0, 5].into_iter().enumerate() {
+                quiz.add_question(format!("Q{}", i), format!("A{}", i), Some(points))
+                    .unwrap();
+            }
+            assert_eq!(quiz.pick_weighted(10), Some(0));
+            assert_eq!(quiz.pick_weighted(4), Some(2));
+            assert_eq!(quiz.pick_weighted(4), quiz.pick_weighted(4));
+            assert!((0..100).all(|seed| quiz.pick_weighted(seed) != Some(1)));
+        }
+
+        /// We test if users can unregister and clean up, but educators can't.
+        #[ink::test]
+        fn unregister_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            assert_eq!(quiz.unregister(), Err(Error::InvalidCaller));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(quiz.unregister(), Err(Error::InvalidCaller));
+            quiz.register().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+            assert_eq!(quiz.leaderboard(10), vec![(accounts.bob, 1)]);
+            assert_eq!(quiz.unregister(), Ok(()));
+            assert_eq!(quiz.power_level_of(accounts.bob), None);
+            assert_eq!(quiz.score_of(accounts.bob), 0);
+            assert_eq!(quiz.streak_of(accounts.bob), 0);
+            assert!(quiz.leaderboard(10).is_empty());
+        }
+
+        /// We test if unregistering announces the cleared score and drops the
+        /// player from past sessions' leaderboards.
+        #[ink::test]
+        fn unregister_clears_every_session() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = quiz_with_three_questions();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.register().unwrap();
+            quiz.answer_and_score(0, String::from("A0")).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            quiz.end_session().unwrap();
+            quiz.start_session().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            quiz.answer_and_score(1, String::from("A1")).unwrap();
+            assert_eq!(quiz.unregister(), Ok(()));
+
+            assert_eq!(score_updates().last(), Some(&(accounts.bob, 0)));
+            assert!(quiz.leaderboard_for(1, 10).is_empty());
+            assert!(quiz.leaderboard_for(2, 10).is_empty());
+            assert_eq!(quiz.player_count(), 0);
+        }
+
+        /// We test if a quiz created for another account is owned by it, not the deployer.
+        #[ink::test]
+        fn new_with_owner_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut quiz = Quiz::new_with_owner(accounts.bob);
+            assert_eq!(quiz.owner(), accounts.bob);
+            assert_eq!(
+                quiz.power_level_of(accounts.bob),
+                Some(PowerLevel::Educator)
+            );
+            assert_eq!(quiz.power_level_of(accounts.alice), None);
+            assert_eq!(quiz.pause(), Err(Error::InvalidCaller));
+        }
+
+        /// We test if numeric questions report having no answer hash.
+        #[ink::test]
+        fn numeric_question_has_no_answer_hash() {
+            let quiz = quiz_with_numeric_question();
+            assert_eq!(quiz.answer_hash(0), Err(Error::NoAnswerHash));
+        }
+
+        /// We test if equal answers to different questions are stored differently.
+        #[ink::test]
+        fn answer_hashes_differ_per_question() {
+            let mut quiz = Quiz::new();
+            for question in ["What color is the sky?", "What color is the sea?", "Q2"] {
+                quiz.add_question_default(String::from(question), String::from("Blue"))
+                    .unwrap();
+            }
+            assert_ne!(quiz.answer_hash(0), quiz.answer_hash(1));
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.check_answer(1, String::from("Blue")), Ok(true));
+            assert_eq!(quiz.check_answer(1, String::from("Red")), Ok(false));
+            // The last question keeps validating after being moved into a freed slot
+            quiz.remove_question(0).unwrap();
+            assert_eq!(quiz.get(0).unwrap().question, String::from("Q2"));
+            assert_eq!(quiz.check_answer(0, String::from("Blue")), Ok(true));
+        }
+    }
+
+    /// This is how you'd write end-to-end (E2E) or integration tests for ink! contracts.
+    ///
+    /// When running these you need to make sure that you:
+    /// - Compile the tests with the `e2e-tests` feature flag enabled (`--features e2e-tests`)
+    /// - Have `substrate-contracts-node` on your `PATH`, or set `CONTRACTS_NODE` to
+    ///   its path; each test starts its own node
+    /// - Have network access, as building the contracts runs `cargo metadata`
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        use mock_psp22::{MockPsp22Ref, PSP22};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        /// We test if the first correct answerer is paid in the reward token.
+        #[ink_e2e::test]
+        async fn reward_is_paid_in_token(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let token = client
+                .instantiate(
+                    "mock_psp22",
+                    &ink_e2e::alice(),
+                    MockPsp22Ref::new(1000),
+                    0,
+                    None,
+                )
+                .await
+                .expect("token instantiate failed")
+                .account_id;
+            let quiz = client
+                .instantiate("quiz", &ink_e2e::alice(), QuizRef::new(), 0, None)
+                .await
+                .expect("quiz instantiate failed")
+                .account_id;
+
+            let fund = build_message::<MockPsp22Ref>(token.clone())
+                .call(|t| t.transfer(quiz.clone(), 100, Vec::new()));
+            client
+                .call(&ink_e2e::alice(), fund, 0, None)
+                .await
+                .expect("token transfer failed");
+            let setup = [
+                build_message::<QuizRef>(quiz.clone())
+                    .call(|q| q.add_question(String::from("Q"), String::from("A"), Some(1))),
+                build_message::<QuizRef>(quiz.clone()).call(|q| q.set_reward_per_question(10)),
+                build_message::<QuizRef>(quiz.clone())
+                    .call(|q| q.set_reward_token(Some(token.clone()))),
+                build_message::<QuizRef>(quiz.clone()).call(|q| q.start_session()),
+            ];
+            for message in setup {
+                client
+                    .call(&ink_e2e::alice(), message, 0, None)
+                    .await
+                    .expect("quiz setup failed");
+            }
+
+            let answer = build_message::<QuizRef>(quiz.clone())
+                .call(|q| q.answer_and_score(0, String::from("A")));
+            client
+                .call(&ink_e2e::bob(), answer, 0, None)
+                .await
+                .expect("answer failed");
+
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let balance = build_message::<MockPsp22Ref>(token.clone()).call(|t| t.balance_of(bob));
+            let balance = client
+                .call_dry_run(&ink_e2e::alice(), &balance, 0, None)
+                .await
+                .return_value();
+            assert_eq!(balance, 10);
+            Ok(())
+        }
+    }
 }
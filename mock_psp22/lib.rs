@@ -0,0 +1,78 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::mock_psp22::{MockPsp22, MockPsp22Ref, PSP22Error, PSP22};
+
+/// Minimal PSP22 token used to test quiz rewards end-to-end
+#[ink::contract]
+mod mock_psp22 {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+        ZeroRecipientAddress,
+        ZeroSenderAddress,
+        SafeTransferCheckFailed(String),
+    }
+
+    #[ink::trait_definition]
+    pub trait PSP22 {
+        /// Returns the token balance of `owner`
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance;
+
+        /// Moves `value` tokens from the caller to `to`
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error>;
+    }
+
+    #[ink(storage)]
+    pub struct MockPsp22 {
+        balances: Mapping<AccountId, Balance>,
+    }
+
+    impl MockPsp22 {
+        /// Mints `supply` tokens to the caller.
+        #[ink(constructor)]
+        pub fn new(supply: Balance) -> Self {
+            let mut balances = Mapping::default();
+            balances.insert(Self::env().caller(), &supply);
+            Self { balances }
+        }
+    }
+
+    impl PSP22 for MockPsp22 {
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.balances.insert(from, &(from_balance - value));
+            let to_balance = self.balance_of(to);
+            self.balances.insert(to, &(to_balance + value));
+            Ok(())
+        }
+    }
+}